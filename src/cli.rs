@@ -3,44 +3,61 @@ use rayon::prelude::*;
 use std::io::{self, Write};
 use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
-    Arc,
+    Arc, Mutex,
 };
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tron_vanity::*;
 
 struct Config {
-    patterns: Vec<String>,
+    patterns: Vec<Pattern>,
     output_file: String,
     save_all: bool,
     batch_size: usize,
     num_threads: usize,
+    mode: SearchMode,
+    hd_range: HdScanRange,
+    /// 助记词 / HD 扫描模式下各链的派生路径与 BIP39 密码
+    mnemonic: MnemonicOptions,
+    /// 最低评分，大于 0 时按评分判定靓号（代替模式匹配）
+    min_score: u32,
+    /// 评分模式下保留的前 N 名
+    top_n: usize,
+    /// 设置后 TRON/EVM 命中只以加密 Keystore 形式保存，明文私钥不落盘
+    keystore: Option<KeystoreExport>,
+    /// 设置后 SOL 命中另存为 solana-keygen 密钥对文件（该目录下 <地址>.json）
+    sol_keypair_dir: Option<String>,
+}
+
+/// Keystore V3 导出设置
+struct KeystoreExport {
+    dir: String,
+    password: SecretString,
+    kdf: KeystoreKdf,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
-            patterns: vec![
-                "1111".to_string(),
-                "2222".to_string(),
-                "3333".to_string(),
-                "4444".to_string(),
-                "5555".to_string(),
-                "6666".to_string(),
-                "7777".to_string(),
-                "8888".to_string(),
-                "9999".to_string(),
-                "0000".to_string(),
-                "AAAA".to_string(),
-                "BBBB".to_string(),
-                "CCCC".to_string(),
-                "DDDD".to_string(),
-            ],
+            patterns: [
+                "1111", "2222", "3333", "4444", "5555", "6666", "7777", "8888", "9999", "0000",
+                "AAAA", "BBBB", "CCCC", "DDDD",
+            ]
+            .iter()
+            .map(|text| Pattern::new(MatchKind::Suffix, text))
+            .collect(),
             output_file: "tron_vanity.txt".to_string(),
             save_all: false,
             batch_size: 1000,
             num_threads: num_cpus::get(),
+            mode: SearchMode::Mnemonic,
+            hd_range: HdScanRange::default(),
+            mnemonic: MnemonicOptions::default(),
+            min_score: 0,
+            top_n: 10,
+            keystore: None,
+            sol_keypair_dir: None,
         }
     }
 }
@@ -82,14 +99,14 @@ fn main() {
         "2" => {
             // 自定义靓号
             config.patterns.clear();
-            print!("输入想要的靓号模式 (逗号分隔，如: 1111,AAAA,好运): ");
+            print_pattern_syntax();
+            print!("输入想要的靓号模式 (逗号分隔，如: 1111,^AAAA,*8888*,@2:666): ");
             io::stdout().flush().unwrap();
             let input = get_user_input("");
-            config.patterns = input
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
+            match checked_patterns(&input, &ChainType::all().collect::<Vec<_>>()) {
+                Ok(patterns) => config.patterns = patterns,
+                Err(e) => println!("{}", e.to_string().red()),
+            }
 
             if config.patterns.is_empty() {
                 println!("{}", "未输入模式，使用默认".red());
@@ -108,6 +125,44 @@ fn main() {
             configure_advanced(&mut config);
             run_vanity_generator(&config);
         }
+        "5" => {
+            // 分离密钥：请求方生成密钥对
+            run_split_key_request();
+        }
+        "6" => {
+            // 分离密钥：搜索方（不可信机器）寻找部分私钥
+            run_split_key_search(&config);
+        }
+        "7" => {
+            // 分离密钥：请求方合成最终私钥
+            run_split_key_combine(&config);
+        }
+        "8" => {
+            // EVM 前导零地址（gas 优化），默认裸私钥：助记词模式每次尝试都要做 PBKDF2
+            config.mode = SearchMode::RawKey;
+            configure_search_mode(&mut config);
+            configure_keystore(&mut config);
+            run_leading_zero_search(&config);
+        }
+        "9" => {
+            // CREATE2 合约地址盐值搜索
+            run_create2_search(&config);
+        }
+        "10" => {
+            // CREATE 合约地址（按部署 nonce 预测），默认裸私钥：助记词模式每次尝试都要做 PBKDF2
+            config.mode = SearchMode::RawKey;
+            configure_search_mode(&mut config);
+            configure_keystore(&mut config);
+            run_contract_search(&config);
+        }
+        "11" => {
+            // 从已有助记词派生地址（审计旧钱包）
+            run_derive(&mut config);
+        }
+        "12" => {
+            // 重新校验结果文件中的记录
+            run_verify(&config);
+        }
         _ => {
             println!("{}", "无效选择，运行默认模式".red());
             run_vanity_generator(&config);
@@ -121,10 +176,83 @@ fn print_menu() {
     println!("{}", "│  2. 自定义靓号模式 (Custom)        │".bright_blue());
     println!("{}", "│  3. 性能测试 (Benchmark)          │".bright_blue());
     println!("{}", "│  4. 高级设置 (Advanced)            │".bright_blue());
+    println!("{}", "│  5. 分离密钥-请求 (Split request)  │".bright_blue());
+    println!("{}", "│  6. 分离密钥-搜索 (Split search)   │".bright_blue());
+    println!("{}", "│  7. 分离密钥-合成 (Split combine)  │".bright_blue());
+    println!("{}", "│  8. EVM 前导零 (Leading zeros)     │".bright_blue());
+    println!("{}", "│  9. CREATE2 盐值 (CREATE2 salt)    │".bright_blue());
+    println!("{}", "│ 10. 合约地址 (Contract by nonce)   │".bright_blue());
+    println!("{}", "│ 11. 助记词派生 (Derive mnemonic)   │".bright_blue());
+    println!("{}", "│ 12. 校验结果文件 (Verify file)     │".bright_blue());
     println!("{}", "└────────────────────────────────────┘".bright_blue());
     println!();
 }
 
+fn print_pattern_syntax() {
+    println!("{}", "模式语法 | Pattern syntax:".bright_blue());
+    println!("{}", "  8888     后缀 (suffix)".bright_blue());
+    println!("{}", "  ^8888    前缀，T / 0x 之后 (prefix)".bright_blue());
+    println!("{}", "  *8888*   包含 (contains)".bright_blue());
+    println!(
+        "{}",
+        "  @2:8888  T / 0x 之后第 2 位起 (position)".bright_blue()
+    );
+    println!(
+        "{}",
+        "  !^AAAA   加 ! 区分大小写，EVM 按校验和大小写 (case-sensitive)".bright_blue()
+    );
+}
+
+/// 解析并检查模式：所有链都不可能命中时报错，部分链不可能时打印警告
+fn checked_patterns(input: &str, chains: &[ChainType]) -> Result<Vec<Pattern>, VanityError> {
+    let patterns = parse_patterns(input)?;
+    for warning in check_patterns(chains, &patterns)? {
+        println!("{} {}", "⚠".bright_yellow(), warning.to_string().yellow());
+    }
+    Ok(patterns)
+}
+
+fn join_patterns(patterns: &[Pattern]) -> String {
+    patterns
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 按链列出每个模式的期望尝试次数与 50% / 90% 命中概率的预计耗时（rate 为每秒候选数）
+fn print_pattern_difficulty(patterns: &[Pattern], chains: &[ChainType], rate: f64) {
+    let describe = |difficulty: Difficulty| {
+        format!(
+            "期望 {} 次 | 50%: {} | 90%: {}",
+            format_attempts(difficulty.expected_attempts()),
+            format_eta(difficulty.eta_seconds(0.5, 0, rate)),
+            format_eta(difficulty.eta_seconds(0.9, 0, rate))
+        )
+    };
+
+    for pattern in patterns {
+        println!(
+            "  {} [{}]",
+            pattern.to_string().bright_cyan(),
+            pattern.case_label()
+        );
+        for &chain in chains {
+            println!(
+                "    {:<4} {}",
+                chain.label(),
+                describe(estimate_difficulty(chain, pattern))
+            );
+        }
+    }
+    println!(
+        "  {} {} (≈ {:.0} addr/s)",
+        "合计 | Combined:".bright_cyan(),
+        describe(estimate_combined_difficulty(chains, patterns)),
+        rate
+    );
+}
+
 fn get_user_input(prompt: &str) -> String {
     print!("{}", prompt);
     io::stdout().flush().unwrap();
@@ -133,6 +261,33 @@ fn get_user_input(prompt: &str) -> String {
     input
 }
 
+/// 选择生成模式，直接回车保留 config.mode
+fn configure_search_mode(config: &mut Config) {
+    const MODES: [SearchMode; 4] = [
+        SearchMode::Mnemonic,
+        SearchMode::RawKey,
+        SearchMode::Incremental,
+        SearchMode::HdScan,
+    ];
+    let default = MODES
+        .iter()
+        .position(|&mode| mode == config.mode)
+        .unwrap_or(0)
+        + 1;
+    let mode_str = get_user_input(&format!(
+        "生成模式 (Search mode) 1=助记词 Mnemonic / 2=裸私钥 Raw key (极速, 无助记词备份) / 3=递增 Incremental (TRON/EVM 点加, 无助记词备份) / 4=HD 索引扫描 HD scan (一个助记词扫描多个地址索引), default {}: ",
+        default
+    ));
+    if let Some(&mode) = mode_str
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| MODES.get(n.wrapping_sub(1)))
+    {
+        config.mode = mode;
+    }
+}
+
 fn configure_advanced(config: &mut Config) {
     println!(
         "{}",
@@ -147,9 +302,101 @@ fn configure_advanced(config: &mut Config) {
         "╚════════════════════════════════════════════════════════════╝".bright_magenta()
     );
 
+    configure_search_mode(config);
+
+    if config.mode == SearchMode::HdScan {
+        let indices_str = get_user_input(&format!(
+            "每个账户扫描的地址索引数 (Address indices per account, default {}): ",
+            config.hd_range.indices
+        ));
+        if let Ok(n) = indices_str.trim().parse::<u32>() {
+            if n > 0 && n <= 1_000_000 {
+                config.hd_range.indices = n;
+            }
+        }
+
+        let accounts_str = get_user_input(&format!(
+            "扫描的账户数 (Accounts per mnemonic, default {}): ",
+            config.hd_range.accounts
+        ));
+        if let Ok(n) = accounts_str.trim().parse::<u32>() {
+            if n > 0 && n <= 1000 {
+                config.hd_range.accounts = n;
+            }
+        }
+    }
+
+    if matches!(config.mode, SearchMode::Mnemonic | SearchMode::HdScan) {
+        let languages = MnemonicLanguage::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, language)| format!("{}={}", i + 1, language))
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let language_str = get_user_input(&format!(
+            "助记词语言 (Mnemonic language) {}, default 1: ",
+            languages
+        ));
+        if let Some(&language) = language_str
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| MnemonicLanguage::ALL.get(n.wrapping_sub(1)))
+        {
+            config.mnemonic.language = language;
+        }
+
+        let words_str = get_user_input(&format!(
+            "助记词长度 (Mnemonic words 12/15/18/21/24, default {}): ",
+            config.mnemonic.word_count.words()
+        ));
+        if let Some(word_count) = words_str
+            .trim()
+            .parse()
+            .ok()
+            .and_then(WordCount::from_words)
+        {
+            config.mnemonic.word_count = word_count;
+        }
+
+        for chain in ChainType::all() {
+            configure_derivation_path(config, chain);
+        }
+
+        config.mnemonic.passphrase = ask_passphrase(true);
+        if config.mnemonic.uses_passphrase() {
+            println!(
+                "{}",
+                "⚠ 已设置 BIP39 密码：恢复命中结果需要助记词 + 密码，密码不会写入文件，请单独保存！"
+                    .bright_red()
+            );
+        }
+    }
+
     let save_all_str = get_user_input("保存所有生成的地址? (Save all addresses? y/n): ");
     config.save_all = save_all_str.trim().to_lowercase().starts_with('y');
 
+    configure_keystore(config);
+    if config.keystore.is_some() && config.save_all {
+        config.save_all = false;
+        println!(
+            "{}",
+            "Keystore 模式下不保存未命中的明文地址 | Save-all disabled in keystore mode"
+                .bright_yellow()
+        );
+    }
+
+    let keypair_str = get_user_input(
+        "SOL 命中另存为 solana-keygen 密钥对文件并显示 Phantom 私钥? (Export SOL keypairs? y/n): ",
+    );
+    if keypair_str.trim().to_lowercase().starts_with('y') {
+        let dir = get_user_input("密钥对目录 (Keypair directory, default solana-keypairs): ");
+        config.sol_keypair_dir = Some(match dir.trim() {
+            "" => "solana-keypairs".to_string(),
+            dir => dir.to_string(),
+        });
+    }
+
     let threads_str = get_user_input(&format!(
         "线程数 (Number of threads, default {}): ",
         config.num_threads
@@ -170,16 +417,143 @@ fn configure_advanced(config: &mut Config) {
         }
     }
 
+    let score_str = get_user_input(
+        "最低评分 (Min beauty score, 0=按模式匹配 / pattern matching, 建议 40+, default 0): ",
+    );
+    if let Ok(n) = score_str.trim().parse::<u32>() {
+        config.min_score = n;
+    }
+    if config.min_score > 0 {
+        let top_str = get_user_input(&format!("保留前 N 名 (Top N, default {}): ", config.top_n));
+        if let Ok(n) = top_str.trim().parse::<usize>() {
+            if n > 0 && n <= 1000 {
+                config.top_n = n;
+            }
+        }
+    }
+
     println!(
         "{}",
         format!(
-            "配置完成: {} 线程, 批大小 {}",
-            config.num_threads, config.batch_size
+            "配置完成: {} 线程, 批大小 {}, 模式 {}",
+            config.num_threads, config.batch_size, config.mode
         )
         .bright_green()
     );
 }
 
+/// 输入 BIP39 密码（不回显）；密码只保存在内存中，结果文件只记录“已使用”
+///
+/// confirm 为 true 时（生成新地址）需再输入一次，两次不一致视为不使用密码，避免输错后无法恢复。
+fn ask_passphrase(confirm: bool) -> Option<SecretString> {
+    let passphrase = ask_hidden(
+        "BIP39 密码 / 第 25 个词 (Passphrase, 留空不使用 / empty for none, 输入不可见 / hidden): ",
+    );
+    if passphrase.expose_secret().is_empty() {
+        return None;
+    }
+    if confirm
+        && ask_hidden("再次输入密码 (Confirm passphrase): ").expose_secret()
+            != passphrase.expose_secret()
+    {
+        println!(
+            "{}",
+            "两次输入的密码不一致，不使用 BIP39 密码 | Passphrases do not match, none used".red()
+        );
+        return None;
+    }
+    Some(passphrase)
+}
+
+/// 询问是否把 TRON/EVM 命中导出为加密 Keystore V3
+fn configure_keystore(config: &mut Config) {
+    let keystore_str = get_user_input(
+        "TRON/EVM 命中导出为加密 Keystore V3，明文私钥不落盘? (Export hits as keystore? y/n): ",
+    );
+    if keystore_str.trim().to_lowercase().starts_with('y') {
+        config.keystore = ask_keystore_export();
+    }
+}
+
+/// 读取不回显的密码，读取失败视为空
+fn ask_hidden(prompt: &str) -> SecretString {
+    rpassword::prompt_password(prompt)
+        .unwrap_or_default()
+        .into()
+}
+
+/// 输入 Keystore 密码（不回显，需输入两次）、KDF 与输出目录；密码为空或不一致时不导出
+fn ask_keystore_export() -> Option<KeystoreExport> {
+    let password = ask_hidden("Keystore 密码 (Keystore password, 输入不可见 / hidden): ");
+    if password.expose_secret().is_empty() {
+        println!(
+            "{}",
+            "未输入密码，不导出 Keystore | Empty password, keystore disabled".red()
+        );
+        return None;
+    }
+    let confirm = ask_hidden("再次输入密码 (Confirm password): ");
+    if confirm.expose_secret() != password.expose_secret() {
+        println!(
+            "{}",
+            "两次输入的密码不一致，不导出 Keystore | Passwords do not match, keystore disabled"
+                .red()
+        );
+        return None;
+    }
+    let kdf = match get_user_input(
+        "密钥派生函数 (KDF) 1=scrypt (n=262144, 约 256 MB 内存) / 2=scrypt light (n=4096) / 3=pbkdf2, default 1: ",
+    )
+    .trim()
+    {
+        "2" => KeystoreKdf::SCRYPT_LIGHT,
+        "3" => KeystoreKdf::PBKDF2,
+        _ => KeystoreKdf::SCRYPT,
+    };
+    let dir = get_user_input("Keystore 目录 (Keystore directory, default keystore): ");
+    let dir = match dir.trim() {
+        "" => "keystore".to_string(),
+        dir => dir.to_string(),
+    };
+    println!(
+        "{}",
+        "⚠ Keystore 密码不会写入文件，遗失后无法解密，请单独保存！".bright_red()
+    );
+    Some(KeystoreExport { dir, password, kdf })
+}
+
+/// 选择派生路径预设，或直接输入自定义模板（{account} / {index} 为占位符）
+fn configure_derivation_path(config: &mut Config, chain: ChainType) {
+    let presets = chain.chain().path_presets();
+    println!(
+        "{}",
+        format!("{} 派生路径 (Derivation path):", chain.label()).bright_magenta()
+    );
+    for (i, preset) in presets.iter().enumerate() {
+        println!("  {}={} {}", i + 1, preset.name, preset.template);
+    }
+    let input =
+        get_user_input("选择预设或输入自定义模板 (Preset number or custom template), default 1: ");
+    let input = input.trim();
+    let template = match input.parse::<usize>() {
+        _ if input.is_empty() => return,
+        Ok(n) => match presets.get(n.wrapping_sub(1)) {
+            Some(preset) => preset.template,
+            None => {
+                println!(
+                    "{}",
+                    "无效选择，使用默认 | Invalid choice, using default".red()
+                );
+                return;
+            }
+        },
+        Err(_) => input,
+    };
+    if let Err(e) = config.mnemonic.paths.set_template(chain, template) {
+        println!("{}", e.to_string().red());
+    }
+}
+
 fn run_vanity_generator(config: &Config) {
     println!();
     println!(
@@ -195,18 +569,103 @@ fn run_vanity_generator(config: &Config) {
         "╚════════════════════════════════════════════════════════════╝".bright_green()
     );
 
+    let chains: Vec<ChainType> = ChainType::all().collect();
+    // 评分模式没有固定的命中概率，不显示 ETA
+    let difficulty = if config.min_score > 0 {
+        println!(
+            "{}",
+            format!(
+                "评分模式 | Score mode: 最低 {} 分，保留前 {} 名",
+                config.min_score, config.top_n
+            )
+            .bright_yellow()
+        );
+        None
+    } else {
+        println!(
+            "{}",
+            format!("靓号模式 | Patterns: {}", join_patterns(&config.patterns)).bright_yellow()
+        );
+        // 短暂测速，用于估算各模式的预计耗时
+        let measured_rate = measure_rate(
+            config.mode,
+            &chains,
+            config.hd_range,
+            Duration::from_millis(300),
+        )
+        .unwrap_or(0.0);
+        print_pattern_difficulty(&config.patterns, &chains, measured_rate);
+        Some(estimate_combined_difficulty(&chains, &config.patterns))
+    };
     println!(
         "{}",
-        format!("靓号模式 | Patterns: {}", config.patterns.join(", ")).bright_yellow()
+        format!("线程数 | Threads: {}", config.num_threads).bright_yellow()
     );
     println!(
         "{}",
-        format!("线程数 | Threads: {}", config.num_threads).bright_yellow()
+        format!("生成模式 | Mode: {}", config.mode).bright_yellow()
     );
+    if config.mode == SearchMode::HdScan {
+        println!(
+            "{}",
+            format!(
+                "HD 扫描范围 | HD Range: {} 账户 × {} 索引 / 助记词",
+                config.hd_range.accounts, config.hd_range.indices
+            )
+            .bright_yellow()
+        );
+    }
+    if matches!(config.mode, SearchMode::RawKey | SearchMode::Incremental) {
+        println!(
+            "{}",
+            "⚠ 非助记词模式: 命中结果没有助记词备份，只能通过私钥导入！".bright_red()
+        );
+    } else {
+        println!(
+            "{}",
+            format!(
+                "助记词 | Mnemonic: {} / {}",
+                config.mnemonic.language, config.mnemonic.word_count
+            )
+            .bright_yellow()
+        );
+        for &chain in &chains {
+            println!(
+                "{}",
+                format!(
+                    "派生路径 | Path [{}]: {} ({})",
+                    chain.label(),
+                    config.mnemonic.paths.template(chain),
+                    config
+                        .mnemonic
+                        .paths
+                        .preset_name(chain)
+                        .unwrap_or("自定义 | custom")
+                )
+                .bright_yellow()
+            );
+        }
+        if config.mnemonic.uses_passphrase() {
+            println!(
+                "{}",
+                format!("BIP39 密码 | Passphrase: {}", PASSPHRASE_NOTICE).bright_yellow()
+            );
+        }
+    }
     println!(
         "{}",
         format!("输出文件 | Output File: {}", config.output_file).bright_yellow()
     );
+    if let Some(keystore) = &config.keystore {
+        println!(
+            "{}",
+            format!(
+                "Keystore 目录 | Keystore Dir: {} ({}, 仅 TRON/EVM)",
+                keystore.dir, keystore.kdf
+            )
+            .bright_yellow()
+        );
+    }
     println!();
 
     let start = Instant::now();
@@ -232,12 +691,21 @@ fn run_vanity_generator(config: &Config) {
             let total_found = found_clone.load(Ordering::Relaxed);
             let rate = total - last_count;
 
+            let eta = difficulty.map_or(String::new(), |difficulty| {
+                format!(
+                    " | 命中概率: {:.1}% | 50% ETA: {} | 90% ETA: {}",
+                    difficulty.probability_after(total) * 100.0,
+                    format_eta(difficulty.eta_seconds(0.5, total, rate as f64)),
+                    format_eta(difficulty.eta_seconds(0.9, total, rate as f64))
+                )
+            });
             print!(
-                "\r{} {} | {} 个靓号已找到 | 速率: {:.0} addr/s    ",
+                "\r{} {} | {} 个靓号已找到 | 速率: {:.0} addr/s{}    ",
                 "▶".bright_cyan(),
                 format!("已生成 {} 个地址", total).bright_white(),
                 format!("{}", total_found).bright_yellow(),
-                rate as f64
+                rate as f64,
+                eta
             );
             io::stdout().flush().unwrap();
 
@@ -253,21 +721,27 @@ fn run_vanity_generator(config: &Config) {
     });
 
     // 主生成线程
-    let patterns: Vec<&str> = config.patterns.iter().map(|s| s.as_str()).collect();
+    let matcher = PatternMatcher::new(&config.patterns);
+    let mut ranking = BeautyRanking::new(config.top_n);
+    let mut generator = CandidateGenerator::new(config.mode, &chains);
+    generator.set_hd_range(config.hd_range);
+    generator.set_mnemonic_options(config.mnemonic.clone());
 
     loop {
         if should_stop.load(Ordering::Relaxed) {
             break;
         }
 
-        let batch: Vec<_> = (0..config.batch_size)
-            .map(|_| {
-                let mnemonic = generate_mnemonic();
-                generate_from_mnemonic_all(&mnemonic)
-            })
-            .collect();
+        // 批处理大小即一次批量公钥计算（共用一次域求逆）的规模
+        let batch = match generator.next_batch(config.batch_size) {
+            Ok(batch) => batch,
+            Err(e) => {
+                println!("{} {}", "生成失败 | Generation failed:".red(), e);
+                break;
+            }
+        };
 
-        for multi in batch {
+        for candidates in batch {
             if should_stop.load(Ordering::Relaxed) {
                 break;
             }
@@ -275,25 +749,34 @@ fn run_vanity_generator(config: &Config) {
             counter.fetch_add(1, Ordering::Relaxed);
 
             // 检查三条链是否匹配
-            let hits = [
-                (&multi.tron, ChainType::Tron),
-                (&multi.evm, ChainType::Evm),
-                (&multi.sol, ChainType::Sol),
-            ];
-
             let mut matched = false;
-            for (addr, chain) in hits {
-                if is_vanity_address(&addr.address, &patterns) {
+            for addr in &candidates {
+                let reason = if config.min_score > 0 {
+                    let score = beauty_score(addr.chain, &addr.address);
+                    (score.score >= config.min_score).then(|| {
+                        let reason = format!("{} 分 | {}", score.score, score.describe());
+                        ranking.push(ScoredAddress {
+                            address: addr.clone(),
+                            score,
+                        });
+                        reason
+                    })
+                } else {
+                    matcher
+                        .find(addr.chain, &addr.address)
+                        .map(Pattern::to_string)
+                };
+
+                if let Some(reason) = reason {
                     matched = true;
                     found.fetch_add(1, Ordering::Relaxed);
-                    print_multi_address(&multi, chain);
-                    let _ = save_multi_address_to_file(&config.output_file, &multi, chain);
+                    report_hit(config, addr, &reason);
                 }
             }
 
             if !matched && config.save_all {
                 // 默认保存 TRON 以兼容旧格式
-                let _ = save_address_to_file(&config.output_file, &multi.tron, false);
+                let _ = save_address_to_file(&config.output_file, &candidates[0], false);
             }
         }
     }
@@ -352,6 +835,578 @@ fn run_vanity_generator(config: &Config) {
         "{}",
         "╚════════════════════════════════════════════════════════════╝".bright_green()
     );
+
+    if config.min_score > 0 && !ranking.entries().is_empty() {
+        println!();
+        println!(
+            "{}",
+            format!("评分排行 | Top {} by score:", config.top_n).bright_green()
+        );
+        for (rank, entry) in ranking.entries().iter().enumerate() {
+            println!(
+                "{:>3}. {} [{}] {} | {}",
+                rank + 1,
+                format!("{} 分", entry.score.score).bright_yellow(),
+                entry.address.chain.label(),
+                entry.address.address.bright_cyan(),
+                entry.score.describe()
+            );
+        }
+    }
+}
+
+/// 输出并保存命中结果：助记词模式列出三条链，其他模式只记录命中链（HD 扫描附带派生路径）；
+/// 启用 Keystore 导出时只记录命中链（TRON/EVM 只写加密 Keystore，SOL 记录不含助记词），
+/// 启用密钥对导出时 SOL 命中另写 solana-keygen 文件
+fn report_hit(config: &Config, addr: &VanityAddress, reason: &str) {
+    println!();
+    println!(
+        "{} {}",
+        "命中原因 | Matched:".bright_magenta(),
+        reason.bright_cyan()
+    );
+    let multi = match &addr.mnemonic {
+        Some(mnemonic) if config.mode == SearchMode::Mnemonic => {
+            generate_from_mnemonic_with_options(mnemonic.expose_secret(), &config.mnemonic).ok()
+        }
+        _ => None,
+    };
+
+    match multi {
+        // Keystore 模式下不写多链记录：其中含助记词与 TRON/EVM 明文私钥
+        Some(multi) if config.keystore.is_none() => {
+            print_multi_address(&multi, addr.chain);
+            let _ = save_multi_address_to_file(&config.output_file, &multi, addr.chain);
+        }
+        _ => {
+            print_address(addr, true);
+            save_hit(config, addr);
+        }
+    }
+
+    if let (Some(dir), ChainType::Sol) = (&config.sol_keypair_dir, addr.chain) {
+        match save_sol_keypair_to_file(dir, addr) {
+            Ok(path) => println!(
+                "{}",
+                format!("✓ SOL 密钥对已保存 | Keypair saved: {}", path.display()).bright_green()
+            ),
+            Err(e) => println!(
+                "{}",
+                format!("SOL 密钥对保存失败 | Keypair failed: {}", e).red()
+            ),
+        }
+        if let Ok(secret) = sol_phantom_secret(addr) {
+            println!(
+                "{} {}",
+                "Phantom 私钥 | Phantom Secret:".bright_red(),
+                secret.expose_secret().bright_white()
+            );
+        }
+    }
+}
+
+/// 保存命中地址：Keystore 模式下 TRON/EVM 只写加密文件，其余链的记录不含助记词，明文私钥与助记词
+/// 不落盘；否则追加到结果文件
+fn save_hit(config: &Config, addr: &VanityAddress) {
+    let Some(keystore) = &config.keystore else {
+        let _ = save_address_to_file(&config.output_file, addr, true);
+        return;
+    };
+    match save_hit_with_keystore(
+        &config.output_file,
+        &keystore.dir,
+        addr,
+        keystore.password.expose_secret(),
+        keystore.kdf,
+    ) {
+        Ok(Some(path)) => println!(
+            "{}",
+            format!("✓ Keystore 已保存 | Keystore saved: {}", path.display()).bright_green()
+        ),
+        Ok(None) => {}
+        Err(e) => println!(
+            "{}",
+            format!("Keystore 保存失败 | Keystore failed: {}", e).red()
+        ),
+    }
+    if addr.mnemonic.is_some() {
+        println!(
+            "{}",
+            "⚠ 助记词未写入文件，如需备份请手动抄写 | Mnemonic not saved to disk".bright_red()
+        );
+    }
+}
+
+fn ask_chain() -> ChainType {
+    let choices = ChainType::all()
+        .enumerate()
+        .map(|(i, chain)| format!("{}={}", i + 1, chain.label()))
+        .collect::<Vec<_>>()
+        .join(" / ");
+    let input = get_user_input(&format!("选择链 (Chain) {}, default 1: ", choices));
+    input
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| ChainType::all().nth(n.checked_sub(1)?))
+        .unwrap_or(ChainType::Tron)
+}
+
+fn ask_key_bytes(prompt: &str) -> Option<Vec<u8>> {
+    let input = get_user_input(prompt);
+    let input = input.trim().trim_start_matches("0x");
+    hex::decode(input)
+        .ok()
+        .or_else(|| bs58::decode(input).into_vec().ok())
+}
+
+fn ask_private_key(prompt: &str) -> Option<[u8; 32]> {
+    ask_key_bytes(prompt)?.try_into().ok()
+}
+
+fn run_split_key_request() {
+    let chain = ask_chain();
+    let request = match generate_split_key_request(chain) {
+        Ok(request) => request,
+        Err(e) => {
+            println!("{} {}", "生成失败 | Generation failed:".red(), e);
+            return;
+        }
+    };
+
+    println!();
+    println!("{} {}", "链 | Chain:".bright_green(), chain.label());
+    println!(
+        "{} {}",
+        "请求方私钥 (保密!) | Requester Private Key (KEEP SECRET):".bright_red(),
+        request.private_key.expose_secret().bright_white()
+    );
+    println!(
+        "{} {}",
+        "请求方公钥 (交给搜索方) | Requester Public Key (send to worker):".bright_green(),
+        request.public_key.bright_cyan()
+    );
+    println!(
+        "{}",
+        "搜索方找到部分私钥后，使用菜单 7 合成最终私钥".bright_yellow()
+    );
+}
+
+fn run_split_key_search(config: &Config) {
+    let chain = ask_chain();
+    let Some(worker) = ask_key_bytes("请求方公钥 (Requester public key, hex): ")
+        .and_then(|public_key| SplitKeyWorker::new(chain, &public_key).ok())
+    else {
+        println!("{}", "无效的公钥 | Invalid public key".red());
+        return;
+    };
+
+    print_pattern_syntax();
+    let input = get_user_input("输入靓号模式 (逗号分隔，留空使用默认): ");
+    let mut patterns = match checked_patterns(&input, &[chain]) {
+        Ok(patterns) => patterns,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return;
+        }
+    };
+    if patterns.is_empty() {
+        patterns = config.patterns.clone();
+    }
+
+    let output_file = "split_key_results.txt";
+    println!(
+        "{}",
+        format!(
+            "开始搜索 {} 分离密钥，{} 线程，找到第一个结果后停止...",
+            chain.label(),
+            config.num_threads
+        )
+        .bright_yellow()
+    );
+
+    let start = Instant::now();
+    let counter = AtomicU64::new(0);
+    let should_stop = AtomicBool::new(false);
+    // 匹配器只构建一次，各线程只读共享
+    let matcher = PatternMatcher::new(&patterns);
+
+    thread::scope(|scope| {
+        for _ in 0..config.num_threads {
+            scope.spawn(|| {
+                while !should_stop.load(Ordering::Relaxed) {
+                    let result = worker.next_candidate();
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    if matcher.is_match(chain, &result.address)
+                        && !should_stop.swap(true, Ordering::Relaxed)
+                    {
+                        print_split_key_result(&result);
+                        let _ = save_split_key_result_to_file(output_file, &result);
+                    }
+                }
+            });
+        }
+    });
+
+    let elapsed = start.elapsed();
+    let total = counter.load(Ordering::Relaxed);
+    println!(
+        "{} {} | {:.2?} | {:.0} addr/s | {}",
+        "✓".bright_green(),
+        format!("已尝试 {} 个部分私钥", total).bright_white(),
+        elapsed,
+        total as f64 / elapsed.as_secs_f64(),
+        output_file.bright_yellow()
+    );
+}
+
+fn run_split_key_combine(config: &Config) {
+    let chain = ask_chain();
+    let Some(requester) = ask_private_key("请求方私钥 (Requester private key, hex): ") else {
+        println!("{}", "无效的私钥 | Invalid private key".red());
+        return;
+    };
+    let Some(partial) = ask_private_key("部分私钥 (Partial private key, hex): ") else {
+        println!("{}", "无效的私钥 | Invalid private key".red());
+        return;
+    };
+
+    match combine_split_key(chain, &requester, &partial) {
+        Ok(addr) => {
+            if chain.chain().curve() == KeyCurve::Ed25519 {
+                println!(
+                    "{} {}",
+                    "地址 | Address:".bright_green(),
+                    addr.address.bright_cyan()
+                );
+                println!(
+                    "{} {}",
+                    "Ed25519 标量 | Ed25519 Scalar:".bright_red(),
+                    addr.private_key.expose_secret().bright_white()
+                );
+                println!("{} {}", "公钥 | Public Key:".bright_blue(), addr.public_key);
+                println!(
+                    "{}",
+                    "注意: SOL 合成结果是 ed25519 标量私钥，不是 32 字节种子，钱包无法直接导入"
+                        .bright_yellow()
+                );
+            } else {
+                print_address(&addr, true);
+            }
+            let _ = save_combined_split_key_to_file(&config.output_file, &addr);
+        }
+        Err(e) => println!("{} {}", "合成失败 | Combine failed:".red(), e),
+    }
+}
+
+fn run_leading_zero_search(config: &Config) {
+    let unit = match get_user_input(
+        "计数单位 (Unit) 1=半字节 nibble (0 字符) / 2=字节 byte (00), default 1: ",
+    )
+    .trim()
+    {
+        "2" => ZeroUnit::Byte,
+        _ => ZeroUnit::Nibble,
+    };
+    let default_target = match unit {
+        ZeroUnit::Nibble => 6,
+        ZeroUnit::Byte => 3,
+    };
+    let target = get_user_input(&format!(
+        "目标前导零个数 (Target count, default {}): ",
+        default_target
+    ))
+    .trim()
+    .parse::<u32>()
+    .ok()
+    .filter(|n| (1..=20).contains(n))
+    .unwrap_or(default_target);
+
+    let chains = [ChainType::Evm];
+    let difficulty = unit.difficulty(target);
+    let rate = measure_rate(
+        config.mode,
+        &chains,
+        config.hd_range,
+        Duration::from_millis(300),
+    )
+    .unwrap_or(0.0)
+        * config.num_threads as f64;
+    println!(
+        "{}",
+        format!(
+            "目标 {} 个前导零 ({}) | 期望 {} 次 | 50%: {} | 90%: {} | 模式 {}",
+            target,
+            unit.label(),
+            format_attempts(difficulty.expected_attempts()),
+            format_eta(difficulty.eta_seconds(0.5, 0, rate)),
+            format_eta(difficulty.eta_seconds(0.9, 0, rate)),
+            config.mode
+        )
+        .bright_yellow()
+    );
+    println!(
+        "{}",
+        format!(
+            "{} 线程搜索中，刷新最好结果时输出，达到目标或按回车停止...",
+            config.num_threads
+        )
+        .bright_yellow()
+    );
+
+    let start = Instant::now();
+    let counter = AtomicU64::new(0);
+    let should_stop = Arc::new(AtomicBool::new(false));
+    let search = Mutex::new(LeadingZeroSearch::new(unit, target));
+
+    // 停止信号线程：达到目标时不等待回车，直接结束
+    let should_stop_clone = Arc::clone(&should_stop);
+    thread::spawn(move || {
+        let _ = get_user_input("");
+        should_stop_clone.store(true, Ordering::Relaxed);
+    });
+
+    thread::scope(|scope| {
+        for _ in 0..config.num_threads {
+            scope.spawn(|| {
+                let mut generator = CandidateGenerator::new(config.mode, &chains);
+                generator.set_hd_range(config.hd_range);
+                generator.set_mnemonic_options(config.mnemonic.clone());
+                while !should_stop.load(Ordering::Relaxed) {
+                    let hit = match generator.next_leading_zero_batch(config.batch_size, unit) {
+                        Ok(hit) => hit,
+                        Err(e) => {
+                            println!("{} {}", "生成失败 | Generation failed:".red(), e);
+                            should_stop.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                    };
+                    counter.fetch_add(config.batch_size as u64, Ordering::Relaxed);
+
+                    let Some(hit) = hit else { continue };
+                    let mut search = search.lock().unwrap();
+                    if !search.offer(hit.clone()) {
+                        continue;
+                    }
+                    println!();
+                    println!(
+                        "{} {}",
+                        "最好结果 | Best so far:".bright_magenta(),
+                        format!("{} {}", hit.zeros, unit.label()).bright_cyan()
+                    );
+                    print_address(&hit.address, true);
+                    save_hit(config, &hit.address);
+                    if search.is_complete() {
+                        should_stop.store(true, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+
+    let elapsed = start.elapsed();
+    let total = counter.load(Ordering::Relaxed);
+    let best = search
+        .into_inner()
+        .unwrap()
+        .best()
+        .map_or(0, |hit| hit.zeros);
+    println!(
+        "{} {} | 最好 {} 个前导零 | {:.2?} | {:.0} addr/s | {}",
+        "✓".bright_green(),
+        format!("已尝试 {} 个地址", total).bright_white(),
+        best,
+        elapsed,
+        total as f64 / elapsed.as_secs_f64(),
+        config.output_file.bright_yellow()
+    );
+}
+
+fn run_create2_search(config: &Config) {
+    let chain = match get_user_input("选择链 (Chain) 1=TRON / 2=EVM, default 2: ").trim() {
+        "1" => ChainType::Tron,
+        _ => ChainType::Evm,
+    };
+    let deployer = get_user_input("部署者 / 工厂合约地址 (Deployer address): ");
+    // 输入的不是 32 字节哈希时视为完整 initCode，自动计算 keccak256
+    let Some(init_code_hash) =
+        ask_key_bytes("Init code hash (或完整 initCode | or full init code, hex): ").map(|bytes| {
+            if bytes.len() == 32 {
+                bytes
+            } else {
+                keccak256(&bytes).to_vec()
+            }
+        })
+    else {
+        println!("{}", "无效的 hex | Invalid hex".red());
+        return;
+    };
+    let salt_prefix = get_user_input("salt 固定前缀 (Salt prefix hex, 最长 24 字节, 可留空): ");
+    let Ok(salt_prefix) = hex::decode(salt_prefix.trim().trim_start_matches("0x")) else {
+        println!("{}", "无效的 hex | Invalid hex".red());
+        return;
+    };
+    if let Err(e) = Create2Miner::new(chain, &deployer, &init_code_hash, &salt_prefix) {
+        println!("{}", e.to_string().red());
+        return;
+    }
+
+    print_pattern_syntax();
+    let input = get_user_input("输入靓号模式 (逗号分隔，留空使用默认): ");
+    let mut patterns = match checked_patterns(&input, &[chain]) {
+        Ok(patterns) => patterns,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return;
+        }
+    };
+    if patterns.is_empty() {
+        patterns = config.patterns.clone();
+    }
+
+    let output_file = "create2_results.txt";
+    println!(
+        "{}",
+        format!(
+            "开始搜索 {} CREATE2 盐值，{} 线程，找到第一个结果后停止...",
+            chain.label(),
+            config.num_threads
+        )
+        .bright_yellow()
+    );
+
+    let start = Instant::now();
+    let counter = AtomicU64::new(0);
+    let should_stop = AtomicBool::new(false);
+    // 匹配器只构建一次，各线程只读共享
+    let matcher = PatternMatcher::new(&patterns);
+
+    thread::scope(|scope| {
+        for _ in 0..config.num_threads {
+            scope.spawn(|| {
+                // 参数已校验，各线程的随机填充互不重叠
+                let Ok(mut miner) =
+                    Create2Miner::new(chain, &deployer, &init_code_hash, &salt_prefix)
+                else {
+                    return;
+                };
+                while !should_stop.load(Ordering::Relaxed) {
+                    let result = miner.next_candidate();
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    if matcher.is_match(chain, &result.address)
+                        && !should_stop.swap(true, Ordering::Relaxed)
+                    {
+                        print_create2_result(&result);
+                        let _ = save_create2_result_to_file(output_file, &result);
+                    }
+                }
+            });
+        }
+    });
+
+    let elapsed = start.elapsed();
+    let total = counter.load(Ordering::Relaxed);
+    println!(
+        "{} {} | {:.2?} | {:.0} salt/s | {}",
+        "✓".bright_green(),
+        format!("已尝试 {} 个盐值", total).bright_white(),
+        elapsed,
+        total as f64 / elapsed.as_secs_f64(),
+        output_file.bright_yellow()
+    );
+}
+
+fn run_contract_search(config: &Config) {
+    // 每个部署账户都要逐个检查 0..=max_nonce，上限避免单个账户耗时过长
+    const MAX_NONCE: u64 = 1000;
+    let max_nonce = get_user_input(&format!(
+        "检查的最大 nonce (Max nonce ≤ {}, 0=只看第一个合约, default 0): ",
+        MAX_NONCE
+    ))
+    .trim()
+    .parse::<u64>()
+    .unwrap_or(0)
+    .min(MAX_NONCE);
+
+    print_pattern_syntax();
+    let input = get_user_input("输入合约地址的靓号模式 (逗号分隔，留空使用默认): ");
+    let mut patterns = match checked_patterns(&input, &[ChainType::Evm]) {
+        Ok(patterns) => patterns,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return;
+        }
+    };
+    if patterns.is_empty() {
+        patterns = config.patterns.clone();
+    }
+
+    let output_file = "contract_results.txt";
+    println!(
+        "{}",
+        format!(
+            "开始搜索 nonce 0..={} 的合约地址，模式 {}，{} 线程，找到第一个结果或按回车停止...",
+            max_nonce, config.mode, config.num_threads
+        )
+        .bright_yellow()
+    );
+
+    let start = Instant::now();
+    let counter = AtomicU64::new(0);
+    let should_stop = Arc::new(AtomicBool::new(false));
+    // 匹配器只构建一次，各线程只读共享
+    let matcher = PatternMatcher::new(&patterns);
+
+    let should_stop_clone = Arc::clone(&should_stop);
+    thread::spawn(move || {
+        let _ = get_user_input("");
+        should_stop_clone.store(true, Ordering::Relaxed);
+    });
+
+    thread::scope(|scope| {
+        for _ in 0..config.num_threads {
+            scope.spawn(|| {
+                let mut generator = CandidateGenerator::new(config.mode, &[ChainType::Evm]);
+                generator.set_hd_range(config.hd_range);
+                generator.set_mnemonic_options(config.mnemonic.clone());
+                while !should_stop.load(Ordering::Relaxed) {
+                    let hits =
+                        match generator.next_contract_batch(config.batch_size, max_nonce, &matcher)
+                        {
+                            Ok(hits) => hits,
+                            Err(e) => {
+                                println!("{} {}", "生成失败 | Generation failed:".red(), e);
+                                should_stop.store(true, Ordering::Relaxed);
+                                break;
+                            }
+                        };
+                    counter.fetch_add(config.batch_size as u64, Ordering::Relaxed);
+                    if let Some(hit) = hits.first() {
+                        if !should_stop.swap(true, Ordering::Relaxed) {
+                            println!();
+                            print_contract_hit(hit);
+                            if config.keystore.is_some() {
+                                save_hit(config, &hit.deployer);
+                            } else {
+                                let _ = save_contract_hit_to_file(output_file, hit);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let elapsed = start.elapsed();
+    let total = counter.load(Ordering::Relaxed);
+    println!(
+        "{} {} | {:.2?} | {:.0} addr/s | {}",
+        "✓".bright_green(),
+        format!("已尝试 {} 个部署账户", total).bright_white(),
+        elapsed,
+        total as f64 / elapsed.as_secs_f64(),
+        output_file.bright_yellow()
+    );
 }
 
 fn benchmark_generation() {
@@ -396,6 +1451,25 @@ fn benchmark_generation() {
     );
     println!();
 
+    // 裸私钥模式单线程测试
+    println!(
+        "{}",
+        "► 裸私钥模式单线程测试 (Raw key single-threaded benchmark)...".bright_green()
+    );
+    let start = Instant::now();
+    for _ in 0..1000 {
+        let _ = generate_raw_tron_address();
+    }
+    let elapsed = start.elapsed();
+    let rate = 1000.0 / elapsed.as_secs_f64();
+    println!(
+        "{} 1000 个地址生成耗时: {:.2?} | 速率: {:.0} addr/s",
+        "✓".bright_green(),
+        elapsed,
+        rate
+    );
+    println!();
+
     // 多线程测试
     println!(
         "{}",
@@ -449,3 +1523,156 @@ fn benchmark_generation() {
         "Tip: Use multi-threaded mode in production for best performance".bright_yellow()
     );
 }
+
+/// 从已有助记词派生地址：按所选链、派生路径与账户 / 索引范围输出地址、公钥与私钥
+fn run_derive(config: &mut Config) {
+    let mnemonic: SecretString = get_user_input("输入助记词 (Mnemonic): ")
+        .trim()
+        .to_string()
+        .into();
+    let language = match mnemonic_language(mnemonic.expose_secret()) {
+        Ok(language) => language,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return;
+        }
+    };
+    println!(
+        "{}",
+        format!("助记词语言 | Language: {}", language).bright_yellow()
+    );
+    config.mnemonic.passphrase = ask_passphrase(false);
+
+    let choices = ChainType::all()
+        .enumerate()
+        .map(|(i, chain)| format!("{}={}", i + 1, chain.label()))
+        .collect::<Vec<_>>()
+        .join(" / ");
+    let input = get_user_input(&format!(
+        "选择链 (Chains) {}, 逗号分隔, 留空为全部: ",
+        choices
+    ));
+    let mut chains: Vec<ChainType> = input
+        .split(',')
+        .filter_map(|n| ChainType::all().nth(n.trim().parse::<usize>().ok()?.checked_sub(1)?))
+        .collect();
+    chains.dedup();
+    if chains.is_empty() {
+        chains = ChainType::all().collect();
+    }
+    for &chain in &chains {
+        configure_derivation_path(config, chain);
+    }
+
+    let Some(accounts) = parse_index_range(
+        &get_user_input("账户范围 (Accounts, 如 0 或 0-2, default 0): "),
+        0..1,
+    ) else {
+        println!("{}", "账户范围无效 | Invalid account range".red());
+        return;
+    };
+    let Some(indices) = parse_index_range(
+        &get_user_input("地址索引范围 (Indices, 如 0 或 0-19, default 0-4): "),
+        0..5,
+    ) else {
+        println!("{}", "索引范围无效 | Invalid index range".red());
+        return;
+    };
+    if accounts.len() * indices.len() * chains.len() > 10_000 {
+        println!(
+            "{}",
+            "范围过大，最多派生 10000 个地址 | Range too large (max 10000)".red()
+        );
+        return;
+    }
+
+    let addresses = match derive_addresses(
+        mnemonic.expose_secret(),
+        &config.mnemonic,
+        &chains,
+        accounts,
+        indices,
+    ) {
+        Ok(addresses) => addresses,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return;
+        }
+    };
+    for addr in &addresses {
+        print_derived_address(addr);
+    }
+
+    let output_file = "derived_addresses.txt";
+    let save = get_user_input(&format!("保存到 {}? (Save? y/n): ", output_file));
+    if save.trim().to_lowercase().starts_with('y') {
+        for addr in &addresses {
+            if let Err(e) = save_address_to_file(output_file, addr, false) {
+                println!("{} {}", "保存失败 | Save failed:".red(), e);
+                return;
+            }
+        }
+        println!(
+            "{}",
+            format!("已保存 {} 条记录到 {}", addresses.len(), output_file).bright_green()
+        );
+    }
+}
+
+/// 重新派生结果文件中的每条记录，逐条输出 OK / MISMATCH / UNPARSEABLE
+fn run_verify(config: &Config) {
+    let input = get_user_input(&format!(
+        "结果文件路径 (Result file, default {}): ",
+        config.output_file
+    ));
+    let filename = match input.trim() {
+        "" => config.output_file.as_str(),
+        filename => filename,
+    };
+    println!(
+        "{}",
+        "标记了 Passphrase 的记录需要 BIP39 密码才能校验助记词 | Records marked with Passphrase need it".bright_yellow()
+    );
+    let passphrase = ask_passphrase(false);
+
+    let reports = match verify_file(
+        filename,
+        passphrase.as_ref().map(SecretString::expose_secret),
+    ) {
+        Ok(reports) => reports,
+        Err(e) => {
+            println!("{} {}", "读取失败 | Read failed:".red(), e);
+            return;
+        }
+    };
+
+    let (mut ok, mut mismatch, mut unparseable) = (0, 0, 0);
+    for report in &reports {
+        let status = match &report.status {
+            VerifyStatus::Ok => {
+                ok += 1;
+                report.status.to_string().bright_green()
+            }
+            VerifyStatus::Mismatch(_) => {
+                mismatch += 1;
+                report.status.to_string().bright_red()
+            }
+            VerifyStatus::Unparseable(_) => {
+                unparseable += 1;
+                report.status.to_string().yellow()
+            }
+        };
+        println!("{:>6}: {} | {}", report.line, report.header, status);
+    }
+    println!(
+        "{}",
+        format!(
+            "共 {} 条 | Total: OK {} / MISMATCH {} / UNPARSEABLE {}",
+            reports.len(),
+            ok,
+            mismatch,
+            unparseable
+        )
+        .bright_cyan()
+    );
+}
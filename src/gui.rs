@@ -4,7 +4,9 @@ use iced::mouse;
 use iced::widget::canvas::{self, path, Canvas};
 use iced::{
     executor, theme,
-    widget::{button, column, container, progress_bar, row, svg, text, text_input, Container},
+    widget::{
+        button, column, container, pick_list, progress_bar, row, svg, text, text_input, Container,
+    },
     Alignment, Application, Border, Color, Command, Element, Font, Length, Settings, Size, Theme,
 };
 use std::sync::{
@@ -51,11 +53,26 @@ fn load_logo() -> svg::Handle {
 pub struct VanityApp {
     // 链选择（多选）
     selected_chains: Vec<ChainType>,
+    // 搜索模式（助记词 / 裸私钥）
+    search_mode: SearchMode,
 
     // 配置
     patterns_input: String,
     batch_size: String,
     thread_count: String,
+    hd_indices: String,
+    hd_accounts: String,
+    // 助记词 / HD 扫描模式下的助记词语言、长度与各链派生路径
+    language: MnemonicLanguage,
+    word_count: WordCount,
+    derivation_paths: DerivationPaths,
+    // BIP39 密码（第 25 个词），只保存在内存中
+    passphrase: SecretString,
+    // Keystore 密码：非空时 TRON/EVM 命中只保存加密 Keystore
+    keystore_password: SecretString,
+    // 最低评分（0 = 按模式匹配）与排行保留数
+    min_score: String,
+    top_n: String,
 
     // 状态
     is_running: bool,
@@ -68,6 +85,10 @@ pub struct VanityApp {
     total_found: u64,
     last_count: u64,
     generation_rate: f64,
+    // 当前搜索的合计难度与模式（用于命中概率与 ETA）
+    difficulty: Difficulty,
+    active_patterns: Vec<Pattern>,
+    eta_logged: bool,
 
     // 系统监控
     cpu_percent: f32,
@@ -86,10 +107,20 @@ pub struct VanityApp {
     found_count: Arc<AtomicU64>,
     // 缓存匹配结果，附带完整三链展示文本
     vanity_cache: Arc<Mutex<Option<(VanityAddress, String)>>>,
+    // 工作线程遇到的错误（派生失败等），由 Tick 写入日志
+    error_cache: Arc<Mutex<Option<String>>>,
+    // 评分模式下本次会话得分最高的结果
+    ranking: Arc<Mutex<BeautyRanking>>,
 
     // 最近发现的靓号（用于手动保存）
     last_found: Option<VanityAddress>,
 
+    // 助记词派生面板：已有助记词、账户 / 索引范围与派生结果（使用上方的链、路径与密码）
+    derive_mnemonic: SecretString,
+    derive_accounts: String,
+    derive_indices: String,
+    derived: Vec<VanityAddress>,
+
     // 内嵌 logo 资源
     logo_handle: svg::Handle,
 
@@ -101,8 +132,18 @@ impl Default for VanityApp {
     fn default() -> Self {
         Self {
             selected_chains: vec![ChainType::Tron],
+            search_mode: SearchMode::Mnemonic,
             batch_size: "1000".to_string(),
             thread_count: num_cpus::get().to_string(),
+            hd_indices: HdScanRange::default().indices.to_string(),
+            hd_accounts: HdScanRange::default().accounts.to_string(),
+            language: MnemonicLanguage::default(),
+            word_count: WordCount::default(),
+            derivation_paths: DerivationPaths::default(),
+            passphrase: SecretString::from(""),
+            keystore_password: SecretString::from(""),
+            min_score: "0".to_string(),
+            top_n: "10".to_string(),
             patterns_input: "1111,2222,3333,4444,5555,6666,7777,8888,9999,0000".to_string(),
             is_running: false,
             is_paused: false,
@@ -112,6 +153,9 @@ impl Default for VanityApp {
             total_found: 0,
             last_count: 0,
             generation_rate: 0.0,
+            difficulty: Difficulty { probability: 0.0 },
+            active_patterns: Vec::new(),
+            eta_logged: false,
             cpu_percent: 0.0,
             memory_percent: 0.0,
             memory_used_mb: 0,
@@ -123,7 +167,13 @@ impl Default for VanityApp {
             gen_count: Arc::new(AtomicU64::new(0)),
             found_count: Arc::new(AtomicU64::new(0)),
             vanity_cache: Arc::new(Mutex::new(None)),
+            error_cache: Arc::new(Mutex::new(None)),
+            ranking: Arc::new(Mutex::new(BeautyRanking::new(10))),
             last_found: None,
+            derive_mnemonic: SecretString::from(""),
+            derive_accounts: "0".to_string(),
+            derive_indices: "0-4".to_string(),
+            derived: Vec::new(),
             logo_handle: load_logo(),
             save_file_path: Self::default_save_path(),
         }
@@ -138,14 +188,38 @@ impl VanityApp {
             "vanity_addresses.txt".to_string()
         }
     }
+
+    /// Keystore 文件保存在结果文件同级的 keystore 目录
+    fn keystore_dir(&self) -> String {
+        std::path::Path::new(&self.save_file_path)
+            .parent()
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .join("keystore")
+            .to_string_lossy()
+            .to_string()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     ChainToggled(ChainType),
+    SearchModeSelected(SearchMode),
     PatternsChanged(String),
     BatchSizeChanged(String),
     ThreadCountChanged(String),
+    MinScoreChanged(String),
+    TopNChanged(String),
+    HdIndicesChanged(String),
+    HdAccountsChanged(String),
+    LanguageSelected(MnemonicLanguage),
+    WordCountSelected(WordCount),
+    PathPresetSelected(ChainType, &'static str),
+    PassphraseChanged(String),
+    KeystorePasswordChanged(String),
+    DeriveMnemonicChanged(String),
+    DeriveAccountsChanged(String),
+    DeriveIndicesChanged(String),
+    DerivePressed,
     ChooseSaveFile,
     SaveFileSelected(Option<std::path::PathBuf>),
     StartPressed,
@@ -178,9 +252,43 @@ impl Application for VanityApp {
                     self.selected_chains.push(chain);
                 }
             }
+            Message::SearchModeSelected(mode) => {
+                if !self.is_running {
+                    self.search_mode = mode;
+                }
+            }
             Message::PatternsChanged(input) => self.patterns_input = input,
             Message::BatchSizeChanged(input) => self.batch_size = input,
             Message::ThreadCountChanged(input) => self.thread_count = input,
+            Message::MinScoreChanged(input) => self.min_score = input,
+            Message::TopNChanged(input) => self.top_n = input,
+            Message::HdIndicesChanged(input) => self.hd_indices = input,
+            Message::HdAccountsChanged(input) => self.hd_accounts = input,
+            Message::LanguageSelected(language) => {
+                if !self.is_running {
+                    self.language = language;
+                }
+            }
+            Message::WordCountSelected(word_count) => {
+                if !self.is_running {
+                    self.word_count = word_count;
+                }
+            }
+            Message::PathPresetSelected(chain, template) => {
+                if !self.is_running {
+                    let _ = self.derivation_paths.set_template(chain, template);
+                }
+            }
+            Message::PassphraseChanged(input) => {
+                if !self.is_running {
+                    self.passphrase = input.into();
+                }
+            }
+            Message::KeystorePasswordChanged(input) => {
+                if !self.is_running {
+                    self.keystore_password = input.into();
+                }
+            }
             Message::ChooseSaveFile => {
                 return Command::perform(
                     async {
@@ -202,6 +310,18 @@ impl Application for VanityApp {
             }
             Message::StartPressed => {
                 if !self.is_running && !self.selected_chains.is_empty() {
+                    let checked = parse_patterns(&self.patterns_input).and_then(|patterns| {
+                        let warnings = check_patterns(&self.selected_chains, &patterns)?;
+                        Ok((patterns, warnings))
+                    });
+                    let (patterns, warnings) = match checked {
+                        Ok(checked) => checked,
+                        Err(e) => {
+                            self.log_messages.insert(0, format!("⚠ {}", e));
+                            return Command::none();
+                        }
+                    };
+
                     self.is_running = true;
                     self.is_paused = false;
                     self.total_generated = 0;
@@ -218,93 +338,264 @@ impl Application for VanityApp {
                         .collect::<Vec<_>>()
                         .join(" | ");
                     self.log_messages.push(format!(
-                        "▶ [{}] 启动，开始搜索靓号...",
-                        chains_str
+                        "▶ [{}] 启动，开始搜索靓号... ({})",
+                        chains_str,
+                        self.search_mode.label()
                     ));
+                    for warning in &warnings {
+                        self.log_messages.push(format!("⚠ {}", warning));
+                    }
+                    let min_score: u32 = self.min_score.trim().parse().unwrap_or(0);
+                    let top_n: usize = self.top_n.trim().parse().unwrap_or(10).clamp(1, 1000);
+                    if let Ok(mut ranking) = self.ranking.lock() {
+                        *ranking = BeautyRanking::new(top_n);
+                    }
+                    if min_score > 0 {
+                        self.log_messages.push(format!(
+                            "★ 评分模式: 最低 {} 分，保留前 {} 名",
+                            min_score, top_n
+                        ));
+                    }
+                    for pattern in patterns.iter().filter(|_| min_score == 0) {
+                        let estimates = self
+                            .selected_chains
+                            .iter()
+                            .map(|&chain| {
+                                format!(
+                                    "{} {}",
+                                    chain.label(),
+                                    format_attempts(pattern.difficulty(chain))
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" / ");
+                        self.log_messages.push(format!(
+                            "  {} [{}] 期望尝试: {}",
+                            pattern,
+                            pattern.case_label(),
+                            estimates
+                        ));
+                    }
+                    self.difficulty =
+                        estimate_combined_difficulty(&self.selected_chains, &patterns);
+                    self.active_patterns = patterns.clone();
+                    // 评分模式没有固定的命中概率，不估算 ETA
+                    self.eta_logged = min_score > 0;
+                    if matches!(
+                        self.search_mode,
+                        SearchMode::RawKey | SearchMode::Incremental
+                    ) {
+                        self.log_messages.push(
+                            "⚠ 非助记词模式: 命中结果没有助记词备份，请妥善保存私钥".to_string(),
+                        );
+                    } else {
+                        self.log_messages
+                            .push(format!("  助记词: {} / {}", self.language, self.word_count));
+                        for &chain in &self.selected_chains {
+                            self.log_messages.push(format!(
+                                "  {} 派生路径: {}",
+                                chain.label(),
+                                self.derivation_paths.template(chain)
+                            ));
+                        }
+                        if !self.passphrase.expose_secret().is_empty() {
+                            self.log_messages
+                                .push(format!("  BIP39 密码: {}", PASSPHRASE_NOTICE));
+                        }
+                    }
+                    let keystore = (!self.keystore_password.expose_secret().is_empty())
+                        .then(|| (self.keystore_dir(), self.keystore_password.clone()));
+                    if let Some((dir, _)) = &keystore {
+                        self.log_messages.push(format!(
+                            "  Keystore: {} ({})，TRON/EVM 命中不写明文私钥",
+                            dir,
+                            KeystoreKdf::default()
+                        ));
+                    }
 
                     let stop_signal = Arc::clone(&self.stop_signal);
                     let pause_signal = Arc::clone(&self.pause_signal);
                     let gen_count = Arc::clone(&self.gen_count);
                     let found_count = Arc::clone(&self.found_count);
                     let selected_chains = self.selected_chains.clone();
+                    let search_mode = self.search_mode;
+                    let mnemonic_options = MnemonicOptions {
+                        language: self.language,
+                        word_count: self.word_count,
+                        paths: self.derivation_paths.clone(),
+                        passphrase: Some(self.passphrase.clone()),
+                    };
+                    let defaults = HdScanRange::default();
+                    let hd_range = HdScanRange {
+                        accounts: self.hd_accounts.trim().parse().unwrap_or(defaults.accounts),
+                        indices: self.hd_indices.trim().parse().unwrap_or(defaults.indices),
+                    };
 
                     stop_signal.store(false, Ordering::Relaxed);
                     pause_signal.store(false, Ordering::Relaxed);
                     gen_count.store(0, Ordering::Relaxed);
                     found_count.store(0, Ordering::Relaxed);
 
-                    let patterns: Vec<String> = self
-                        .patterns_input
-                        .split(',')
-                        .map(|s| s.trim().to_string())
-                        .filter(|s| !s.is_empty())
-                        .collect();
-
-                    let batch: u64 = self.batch_size.parse().unwrap_or(1000).max(1);
+                    let batch: usize = self.batch_size.parse().unwrap_or(1000).max(1);
                     let threads: usize = self
                         .thread_count
                         .parse()
                         .unwrap_or_else(|_| num_cpus::get())
                         .clamp(1, num_cpus::get());
 
+                    // 匹配器只构建一次，各线程只读共享
+                    let matcher = Arc::new(PatternMatcher::new(&patterns));
+
                     for _ in 0..threads {
                         let stop = Arc::clone(&stop_signal);
                         let pause = Arc::clone(&pause_signal);
                         let gen = Arc::clone(&gen_count);
                         let found = Arc::clone(&found_count);
                         let vanity_cache = Arc::clone(&self.vanity_cache);
-                        let patterns_clone = patterns.clone();
+                        let error_cache = Arc::clone(&self.error_cache);
+                        let matcher = Arc::clone(&matcher);
+                        let ranking = Arc::clone(&self.ranking);
                         let chains_copy = selected_chains.clone();
                         let save_path = self.save_file_path.clone();
+                        let options = mnemonic_options.clone();
+                        let keystore = keystore.clone();
+
+                        thread::spawn(move || {
+                            let mut generator = CandidateGenerator::new(search_mode, &chains_copy);
+                            generator.set_hd_range(hd_range);
+                            generator.set_mnemonic_options(options.clone());
+                            loop {
+                                if stop.load(Ordering::Relaxed) {
+                                    break;
+                                }
 
-                        thread::spawn(move || loop {
-                            if stop.load(Ordering::Relaxed) {
-                                break;
-                            }
-
-                            if pause.load(Ordering::Relaxed) {
-                                thread::sleep(Duration::from_millis(50));
-                                continue;
-                            }
+                                if pause.load(Ordering::Relaxed) {
+                                    thread::sleep(Duration::from_millis(50));
+                                    continue;
+                                }
 
-                            for _ in 0..batch {
-                                // 生成单个助记词对应的三种链地址
-                                let mnemonic = generate_mnemonic();
-                                let multi_addr = generate_from_mnemonic_all(&mnemonic);
-                                
-                                let patterns_refs: Vec<&str> =
-                                    patterns_clone.iter().map(|s| s.as_str()).collect();
-                                
-                                // 对每个选中的链进行匹配
-                                let addresses = vec![
-                                    (&multi_addr.tron, ChainType::Tron),
-                                    (&multi_addr.evm, ChainType::Evm),
-                                    (&multi_addr.sol, ChainType::Sol),
-                                ];
-                                
-                                for (addr, chain_type) in addresses {
-                                    if chains_copy.contains(&chain_type)
-                                        && is_vanity_address(&addr.address, &patterns_refs)
-                                    {
+                                // 批处理大小即一次批量公钥计算（共用一次域求逆）的规模
+                                let candidates_batch = match generator.next_batch(batch) {
+                                    Ok(candidates_batch) => candidates_batch,
+                                    Err(e) => {
+                                        if let Ok(mut cache) = error_cache.lock() {
+                                            *cache = Some(format!("⚠ 生成失败: {}", e));
+                                        }
+                                        break;
+                                    }
+                                };
+
+                                for candidates in candidates_batch {
+                                    // 选中链的候选地址（助记词模式共享一个助记词）
+                                    for addr in &candidates {
+                                        let reason = if min_score > 0 {
+                                            let score = beauty_score(addr.chain, &addr.address);
+                                            if score.score < min_score {
+                                                continue;
+                                            }
+                                            let reason =
+                                                format!("{} 分: {}", score.score, score.describe());
+                                            if let Ok(mut ranking) = ranking.lock() {
+                                                ranking.push(ScoredAddress {
+                                                    address: addr.clone(),
+                                                    score,
+                                                });
+                                            }
+                                            reason
+                                        } else {
+                                            let Some(pattern) =
+                                                matcher.find(addr.chain, &addr.address)
+                                            else {
+                                                continue;
+                                            };
+                                            pattern.to_string()
+                                        };
                                         found.fetch_add(1, Ordering::Relaxed);
-                                        let _ =
-                                            save_multi_address_to_file(&save_path, &multi_addr, chain_type);
-
-                                        let display = format!(
-                                            "✨ 发现靓号: [{}] {} | TRON: {} | EVM: {} | SOL: {}",
-                                            chain_type.label(),
-                                            addr.address,
-                                            multi_addr.tron.address,
-                                            multi_addr.evm.address,
-                                            multi_addr.sol.address,
-                                        );
+
+                                        let multi_addr = match &addr.mnemonic {
+                                            Some(mnemonic)
+                                                if search_mode == SearchMode::Mnemonic =>
+                                            {
+                                                generate_from_mnemonic_with_options(
+                                                    mnemonic.expose_secret(),
+                                                    &options,
+                                                )
+                                                .ok()
+                                            }
+                                            _ => None,
+                                        };
+
+                                        let display = match (multi_addr, &keystore) {
+                                            // Keystore 模式下不写多链记录：其中含助记词与 TRON/EVM 明文私钥
+                                            (_, Some((dir, password))) => {
+                                                let saved = match save_hit_with_keystore(
+                                                    &save_path,
+                                                    dir,
+                                                    addr,
+                                                    password.expose_secret(),
+                                                    KeystoreKdf::default(),
+                                                ) {
+                                                    Ok(Some(path)) => {
+                                                        format!("Keystore: {}", path.display())
+                                                    }
+                                                    Ok(None) => "助记词未写入文件".to_string(),
+                                                    Err(e) => format!("⚠ Keystore 保存失败: {}", e),
+                                                };
+                                                format!(
+                                                    "✨ 发现靓号: [{}] {} ({}) | {}",
+                                                    addr.chain.label(),
+                                                    addr.address,
+                                                    reason,
+                                                    saved,
+                                                )
+                                            }
+                                            (Some(multi_addr), _) => {
+                                                let _ = save_multi_address_to_file(
+                                                    &save_path,
+                                                    &multi_addr,
+                                                    addr.chain,
+                                                );
+                                                let all_addresses = multi_addr
+                                                    .addresses
+                                                    .iter()
+                                                    .map(|(chain, addr)| {
+                                                        format!(
+                                                            "{}: {}",
+                                                            chain.label(),
+                                                            addr.address
+                                                        )
+                                                    })
+                                                    .collect::<Vec<_>>()
+                                                    .join(" | ");
+                                                format!(
+                                                    "✨ 发现靓号: [{}] {} ({}) | {}",
+                                                    addr.chain.label(),
+                                                    addr.address,
+                                                    reason,
+                                                    all_addresses,
+                                                )
+                                            }
+                                            (None, _) => {
+                                                let _ =
+                                                    save_address_to_file(&save_path, addr, true);
+                                                format!(
+                                                    "✨ 发现靓号: [{}] {} ({}) | {}",
+                                                    addr.chain.label(),
+                                                    addr.address,
+                                                    reason,
+                                                    addr.derivation_path
+                                                        .as_deref()
+                                                        .unwrap_or("裸私钥，无助记词备份"),
+                                                )
+                                            }
+                                        };
 
                                         if let Ok(mut cache) = vanity_cache.lock() {
                                             *cache = Some((addr.clone(), display));
                                         }
                                     }
+                                    gen.fetch_add(1, Ordering::Relaxed);
                                 }
-                                gen.fetch_add(1, Ordering::Relaxed);
                             }
                         });
                     }
@@ -364,6 +655,11 @@ impl Application for VanityApp {
                         self.last_found = Some(vanity_info);
                     }
                 }
+                if let Ok(mut cache) = self.error_cache.lock() {
+                    if let Some(error) = cache.take() {
+                        self.log_messages.insert(0, error);
+                    }
+                }
 
                 if self.is_running && !self.is_paused {
                     let current_gen = self.gen_count.load(Ordering::Relaxed);
@@ -380,6 +676,71 @@ impl Application for VanityApp {
 
                     self.total_generated = current_gen;
                     self.total_found = current_found;
+
+                    // 测得速率后，为每个模式记录一次 50% / 90% 预计耗时
+                    if !self.eta_logged && self.generation_rate > 0.0 {
+                        self.eta_logged = true;
+                        for pattern in &self.active_patterns {
+                            for &chain in &self.selected_chains {
+                                let difficulty = estimate_difficulty(chain, pattern);
+                                self.log_messages.insert(
+                                    0,
+                                    format!(
+                                        "⏱ {} [{}] 50%: {} | 90%: {}",
+                                        pattern,
+                                        chain.label(),
+                                        format_eta(difficulty.eta_seconds(
+                                            0.5,
+                                            0,
+                                            self.generation_rate
+                                        )),
+                                        format_eta(difficulty.eta_seconds(
+                                            0.9,
+                                            0,
+                                            self.generation_rate
+                                        )),
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            Message::DeriveMnemonicChanged(input) => self.derive_mnemonic = input.into(),
+            Message::DeriveAccountsChanged(input) => self.derive_accounts = input,
+            Message::DeriveIndicesChanged(input) => self.derive_indices = input,
+            Message::DerivePressed => {
+                let (Some(accounts), Some(indices)) = (
+                    parse_index_range(&self.derive_accounts, 0..1),
+                    parse_index_range(&self.derive_indices, 0..5),
+                ) else {
+                    self.log_messages
+                        .insert(0, "⚠ 账户或索引范围无效（如 0 或 0-19）".to_string());
+                    return Command::none();
+                };
+                if accounts.len() * indices.len() * self.selected_chains.len() > 1000 {
+                    self.log_messages
+                        .insert(0, "⚠ 范围过大，最多派生 1000 个地址".to_string());
+                    return Command::none();
+                }
+                let options = MnemonicOptions {
+                    paths: self.derivation_paths.clone(),
+                    passphrase: Some(self.passphrase.clone()),
+                    ..Default::default()
+                };
+                match derive_addresses(
+                    self.derive_mnemonic.expose_secret().trim(),
+                    &options,
+                    &self.selected_chains,
+                    accounts,
+                    indices,
+                ) {
+                    Ok(derived) => {
+                        self.log_messages
+                            .insert(0, format!("🔑 已派生 {} 个地址", derived.len()));
+                        self.derived = derived;
+                    }
+                    Err(e) => self.log_messages.insert(0, format!("⚠ 派生失败: {}", e)),
                 }
             }
             Message::VanityFound(_) => {
@@ -390,34 +751,148 @@ impl Application for VanityApp {
     }
 
     fn view(&self) -> Element<'_, Message> {
-        const CHAINS: [ChainType; 3] = [ChainType::Tron, ChainType::Evm, ChainType::Sol];
-
-        // 构建链选择按钮组（多选）
-        let chain_buttons = CHAINS
-            .iter()
-            .fold(row![].spacing(8), |row, &chain| {
-                let is_selected = self.selected_chains.contains(&chain);
-                let btn = if is_selected {
-                    button(
-                        text(chain.label())
-                            .size(14)
-                            .style(iced::theme::Text::Color(Color::from_rgb8(255, 255, 255))),
+        // 构建链选择按钮组（多选，按注册表列出）
+        let chain_buttons = ChainType::all().fold(row![].spacing(8), |row, chain| {
+            let is_selected = self.selected_chains.contains(&chain);
+            let btn = if is_selected {
+                button(
+                    text(chain.label())
+                        .size(14)
+                        .style(iced::theme::Text::Color(Color::from_rgb8(255, 255, 255))),
+                )
+                .padding(10)
+                .style(iced::theme::Button::Positive)
+                .on_press(Message::ChainToggled(chain))
+            } else {
+                button(
+                    text(chain.label())
+                        .size(14)
+                        .style(iced::theme::Text::Color(Color::from_rgb8(142, 162, 185))),
+                )
+                .padding(10)
+                .style(iced::theme::Button::Secondary)
+                .on_press(Message::ChainToggled(chain))
+            };
+            row.push(btn)
+        });
+
+        // 搜索模式选择（单选）
+        let mode_buttons = [
+            SearchMode::Mnemonic,
+            SearchMode::RawKey,
+            SearchMode::Incremental,
+            SearchMode::HdScan,
+        ]
+        .iter()
+        .fold(row![].spacing(8), |row, &mode| {
+            let btn = button(text(mode.label()).size(14))
+                .padding(10)
+                .on_press(Message::SearchModeSelected(mode));
+            let btn = if self.search_mode == mode {
+                btn.style(iced::theme::Button::Positive)
+            } else {
+                btn.style(iced::theme::Button::Secondary)
+            };
+            row.push(btn)
+        });
+
+        let mut mode_row = column![
+            text("生成模式 (裸私钥/递增跳过 BIP39/PBKDF2，速度更快但没有助记词备份)")
+                .size(14)
+                .style(iced::theme::Text::Color(accent())),
+            mode_buttons,
+        ]
+        .spacing(8);
+
+        // 助记词 / HD 扫描模式下为每条已选链选择派生路径预设
+        if matches!(self.search_mode, SearchMode::Mnemonic | SearchMode::HdScan) {
+            let word_count_buttons = WordCount::ALL.iter().fold(
+                row![text("助记词长度").size(14)]
+                    .spacing(8)
+                    .align_items(Alignment::Center),
+                |row, &word_count| {
+                    let btn = button(text(format!("{} 词", word_count.words())).size(13))
+                        .padding(8)
+                        .on_press(Message::WordCountSelected(word_count));
+                    let btn = if self.word_count == word_count {
+                        btn.style(iced::theme::Button::Positive)
+                    } else {
+                        btn.style(iced::theme::Button::Secondary)
+                    };
+                    row.push(btn)
+                },
+            );
+            mode_row = mode_row.push(
+                word_count_buttons.push(text("语言").size(14)).push(
+                    pick_list(
+                        &MnemonicLanguage::ALL[..],
+                        Some(self.language),
+                        Message::LanguageSelected,
                     )
-                    .padding(10)
-                    .style(iced::theme::Button::Positive)
-                    .on_press(Message::ChainToggled(chain))
-                } else {
-                    button(
-                        text(chain.label())
-                            .size(14)
-                            .style(iced::theme::Text::Color(Color::from_rgb8(142, 162, 185))),
+                    .padding(8),
+                ),
+            );
+            for &chain in &self.selected_chains {
+                let current = self.derivation_paths.template(chain);
+                let preset_buttons = chain.chain().path_presets().iter().fold(
+                    row![text(format!("{} 路径", chain.label())).size(14)]
+                        .spacing(8)
+                        .align_items(Alignment::Center),
+                    |row, preset| {
+                        let btn = button(text(preset.name).size(13))
+                            .padding(8)
+                            .on_press(Message::PathPresetSelected(chain, preset.template));
+                        let btn = if preset.template == current {
+                            btn.style(iced::theme::Button::Positive)
+                        } else {
+                            btn.style(iced::theme::Button::Secondary)
+                        };
+                        row.push(btn)
+                    },
+                );
+                mode_row = mode_row.push(
+                    preset_buttons.push(
+                        text(current)
+                            .size(12)
+                            .style(iced::theme::Text::Color(Color::from_rgb8(160, 180, 200))),
+                    ),
+                );
+            }
+            mode_row = mode_row.push(
+                row![
+                    text("BIP39 密码").size(14).width(Length::Shrink),
+                    text_input(
+                        "可选，第 25 个词（不会写入文件，请单独保存）",
+                        self.passphrase.expose_secret()
                     )
+                    .on_input(Message::PassphraseChanged)
+                    .secure(true)
                     .padding(10)
-                    .style(iced::theme::Button::Secondary)
-                    .on_press(Message::ChainToggled(chain))
-                };
-                row.push(btn)
-            });
+                    .width(Length::Fixed(360.0)),
+                ]
+                .spacing(12)
+                .align_items(Alignment::Center),
+            );
+        }
+
+        if self.search_mode == SearchMode::HdScan {
+            mode_row = mode_row.push(
+                row![
+                    text("每账户索引数").size(14).width(Length::Shrink),
+                    text_input("1000", &self.hd_indices)
+                        .on_input(Message::HdIndicesChanged)
+                        .padding(10)
+                        .width(Length::Fixed(120.0)),
+                    text("账户数").size(14).width(Length::Shrink),
+                    text_input("1", &self.hd_accounts)
+                        .on_input(Message::HdAccountsChanged)
+                        .padding(10)
+                        .width(Length::Fixed(120.0)),
+                ]
+                .spacing(12)
+                .align_items(Alignment::Center),
+            );
+        }
 
         let header = row![
             svg(self.logo_handle.clone())
@@ -444,7 +919,7 @@ impl Application for VanityApp {
         .align_items(Alignment::Center);
 
         let patterns_row = column![
-            text("靓号模式 (末尾匹配, 逗号分隔) ")
+            text("靓号模式 (逗号分隔; 8888=末尾, ^8888=开头, *8888*=包含, @2:8888=第 2 位起, 前加 ! 区分大小写)")
                 .size(14)
                 .style(iced::theme::Text::Color(accent())),
             text_input("1111,2222,...", &self.patterns_input)
@@ -465,6 +940,19 @@ impl Application for VanityApp {
             text(&self.save_file_path)
                 .size(12)
                 .style(iced::theme::Text::Color(Color::from_rgb8(160, 180, 200))),
+            row![
+                text("Keystore 密码").size(14).width(Length::Shrink),
+                text_input(
+                    "可选，填写后 TRON/EVM 命中只保存加密 Keystore（明文私钥不落盘）",
+                    self.keystore_password.expose_secret()
+                )
+                .on_input(Message::KeystorePasswordChanged)
+                .secure(true)
+                .padding(10)
+                .width(Length::Fixed(420.0)),
+            ]
+            .spacing(12)
+            .align_items(Alignment::Center),
         ]
         .spacing(6);
 
@@ -479,6 +967,16 @@ impl Application for VanityApp {
                 .on_input(Message::ThreadCountChanged)
                 .padding(10)
                 .width(Length::Fixed(120.0)),
+            text("最低评分 (0=模式)").size(14).width(Length::Shrink),
+            text_input("0", &self.min_score)
+                .on_input(Message::MinScoreChanged)
+                .padding(10)
+                .width(Length::Fixed(80.0)),
+            text("Top N").size(14).width(Length::Shrink),
+            text_input("10", &self.top_n)
+                .on_input(Message::TopNChanged)
+                .padding(10)
+                .width(Length::Fixed(80.0)),
         ]
         .spacing(12)
         .align_items(Alignment::Center);
@@ -506,6 +1004,35 @@ impl Application for VanityApp {
         .width(Length::Fill)
         .align_items(Alignment::Start);
 
+        let eta_cards = row![
+            metric_card(
+                "命中概率",
+                &format!(
+                    "{:.1}%",
+                    self.difficulty.probability_after(self.total_generated) * 100.0
+                )
+            ),
+            metric_card(
+                "50% ETA",
+                &format_eta(self.difficulty.eta_seconds(
+                    0.5,
+                    self.total_generated,
+                    self.generation_rate
+                ))
+            ),
+            metric_card(
+                "90% ETA",
+                &format_eta(self.difficulty.eta_seconds(
+                    0.9,
+                    self.total_generated,
+                    self.generation_rate
+                ))
+            ),
+        ]
+        .spacing(12)
+        .width(Length::Fill)
+        .align_items(Alignment::Start);
+
         let system_card = card(
             column![
                 text("系统监控·仪表模式")
@@ -553,12 +1080,88 @@ impl Application for VanityApp {
                 .height(Length::Fixed(240.0))
         };
 
+        // 评分排行：按分数从高到低
+        let ranking = {
+            let mut col = column![text("评分排行")
+                .size(18)
+                .style(iced::theme::Text::Color(accent()))];
+            if let Ok(ranking) = self.ranking.lock() {
+                for (rank, entry) in ranking.entries().iter().enumerate() {
+                    col = col.push(
+                        text(format!(
+                            "#{} {} 分 [{}] {} | {}",
+                            rank + 1,
+                            entry.score.score,
+                            entry.address.chain.label(),
+                            entry.address.address,
+                            entry.score.describe()
+                        ))
+                        .size(13),
+                    );
+                }
+            }
+            card(col.spacing(6)).width(Length::Fill)
+        };
+
+        // 助记词派生面板：链、派生路径与 BIP39 密码沿用上方设置
+        let derive_panel = {
+            let mut col = column![
+                text("助记词派生 (审计已有钱包，使用上方选择的链、派生路径与 BIP39 密码)")
+                    .size(18)
+                    .style(iced::theme::Text::Color(accent())),
+                text_input(
+                    "输入已有助记词（任意 BIP39 语言）",
+                    self.derive_mnemonic.expose_secret()
+                )
+                .on_input(Message::DeriveMnemonicChanged)
+                .secure(true)
+                .padding(10)
+                .width(Length::Fill),
+                row![
+                    text("账户").size(14).width(Length::Shrink),
+                    text_input("0", &self.derive_accounts)
+                        .on_input(Message::DeriveAccountsChanged)
+                        .padding(10)
+                        .width(Length::Fixed(100.0)),
+                    text("索引").size(14).width(Length::Shrink),
+                    text_input("0-4", &self.derive_indices)
+                        .on_input(Message::DeriveIndicesChanged)
+                        .padding(10)
+                        .width(Length::Fixed(100.0)),
+                    primary_button("派生", Message::DerivePressed),
+                ]
+                .spacing(12)
+                .align_items(Alignment::Center),
+            ]
+            .spacing(8);
+            for addr in &self.derived {
+                col = col.push(
+                    column![
+                        text(format!(
+                            "[{}] {} | {}",
+                            addr.chain.label(),
+                            addr.derivation_path.as_deref().unwrap_or_default(),
+                            addr.address
+                        ))
+                        .size(13)
+                        .style(iced::theme::Text::Color(accent())),
+                        text(format!("公钥: {}", addr.public_key)).size(12),
+                        text(format!("私钥: {}", addr.private_key.expose_secret())).size(12),
+                    ]
+                    .spacing(2),
+                );
+            }
+            card(col).width(Length::Fill)
+        };
+
         let layout = column![
             header,
-            card(column![file_path_row, patterns_row, batch_threads_row].spacing(12)),
+            card(column![file_path_row, mode_row, patterns_row, batch_threads_row].spacing(12)),
             card(column![controls].spacing(8)),
-            card(stat_cards),
+            derive_panel,
+            card(column![stat_cards, eta_cards].spacing(12)),
             system_card,
+            ranking,
             logs,
         ]
         .spacing(14)
@@ -726,7 +1329,7 @@ impl canvas::Program<Message> for Gauge {
 
         // 文本
         frame.fill_text(canvas::Text {
-            content: format!("{}", self.label),
+            content: self.label.clone(),
             position: center + iced::Vector::new(0.0, -12.0),
             color: Color::from_rgb8(210, 220, 235),
             size: iced::Pixels(16.0),
@@ -1,14 +1,24 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
 use std::{fs::OpenOptions, io::Write};
 
 use chrono::Local;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar as Ed25519Scalar;
 use ed25519_dalek::{PublicKey, SecretKey};
-use ed25519_dalek_bip32::{DerivationPath as Ed25519DerivationPath, ExtendedSecretKey};
-use k256::elliptic_curve::sec1::ToEncodedPoint;
+use ed25519_dalek_bip32::{ChildIndex, DerivationPath as Ed25519DerivationPath, ExtendedSecretKey};
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::{sec1::ToEncodedPoint, BatchNormalize, PrimeField};
+use k256::{ProjectivePoint, Scalar};
 use rand::RngCore;
 use rust_embed::RustEmbed;
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha512};
+use subtle::ConstantTimeEq;
 use tiny_keccak::{Hasher, Keccak};
 use bip39::Mnemonic;
+use zeroize::Zeroize;
 
 pub mod monitor;
 
@@ -16,8 +26,85 @@ pub mod monitor;
 #[folder = "assets"]
 pub struct Assets;
 
-/// 支持的链类型
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// 库内所有可失败操作的错误类型
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum VanityError {
+    /// 助记词无效（单词不在词表、长度或校验和错误等）
+    InvalidMnemonic(String),
+    /// 私钥无效（为 0、超出曲线阶或长度不对）
+    InvalidPrivateKey,
+    /// 公钥无效（格式错误或不在曲线上）
+    InvalidPublicKey,
+    /// 派生路径无法解析
+    InvalidDerivationPath(String),
+    /// BIP32 / SLIP-0010 派生失败
+    Derivation(String),
+    /// 熵长度无效，无法生成助记词
+    InvalidEntropy(String),
+    /// 地址格式或校验和错误
+    InvalidAddress(String),
+    /// 该链不支持此功能
+    UnsupportedChain(ChainType),
+    /// 输入参数无效（长度或取值超出范围）
+    InvalidParameter(String),
+    /// 靓号模式语法错误
+    InvalidPattern(String),
+    /// 模式在该链地址中不可能出现：含字母表外的字符（character 为该字符），或超出地址长度（character 为 None）
+    ImpossiblePattern {
+        chain: ChainType,
+        pattern: String,
+        character: Option<char>,
+        alphabet: &'static str,
+    },
+    /// Keystore JSON 格式错误或参数不受支持
+    Keystore(String),
+    /// Keystore 的 MAC 校验失败：密码错误或文件被篡改
+    WrongPassword,
+}
+
+impl std::fmt::Display for VanityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VanityError::InvalidMnemonic(e) => write!(f, "助记词无效 | invalid mnemonic: {}", e),
+            VanityError::InvalidPrivateKey => write!(f, "私钥无效 | invalid private key"),
+            VanityError::InvalidPublicKey => write!(f, "公钥无效 | invalid public key"),
+            VanityError::InvalidDerivationPath(path) => {
+                write!(f, "派生路径无效 | invalid derivation path: {}", path)
+            }
+            VanityError::Derivation(e) => write!(f, "密钥派生失败 | key derivation failed: {}", e),
+            VanityError::InvalidEntropy(e) => write!(f, "熵无效 | invalid entropy: {}", e),
+            VanityError::InvalidAddress(address) => write!(f, "地址无效 | invalid address: {}", address),
+            VanityError::UnsupportedChain(chain) => write!(f, "{} 不支持该功能 | unsupported chain", chain),
+            VanityError::InvalidParameter(e) => write!(f, "参数无效 | invalid parameter: {}", e),
+            VanityError::InvalidPattern(e) => write!(f, "靓号模式无效 | invalid pattern: {}", e),
+            VanityError::ImpossiblePattern {
+                chain,
+                pattern,
+                character: Some(c),
+                alphabet,
+            } => write!(
+                f,
+                "{} 地址不可能出现模式 {} 中的字符 '{}' | impossible character; 可用字符 | valid: {}",
+                chain, pattern, c, alphabet
+            ),
+            VanityError::ImpossiblePattern { chain, pattern, .. } => write!(
+                f,
+                "模式 {} 超出 {} 地址长度 | pattern longer than address",
+                pattern, chain
+            ),
+            VanityError::Keystore(e) => write!(f, "Keystore 无效 | invalid keystore: {}", e),
+            VanityError::WrongPassword => write!(
+                f,
+                "Keystore 密码错误或文件已损坏 | wrong keystore password or corrupted file"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VanityError {}
+
+/// 支持的链类型（链标识，具体行为由 [`CHAIN_REGISTRY`] 中的 [`Chain`] 实现提供）
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ChainType {
     Tron,
     Evm,
@@ -25,12 +112,34 @@ pub enum ChainType {
 }
 
 impl ChainType {
+    /// 注册表中的所有链，按注册顺序
+    pub fn all() -> impl Iterator<Item = ChainType> {
+        CHAIN_REGISTRY.iter().map(|chain| chain.chain_type())
+    }
+
+    /// 该链在注册表中的实现
+    pub fn chain(self) -> &'static dyn Chain {
+        // 注册表顺序与声明顺序一致（由测试保证）
+        CHAIN_REGISTRY[self as usize]
+    }
+
     pub fn label(self) -> &'static str {
-        match self {
-            ChainType::Tron => "TRON",
-            ChainType::Evm => "EVM",
-            ChainType::Sol => "SOL",
-        }
+        self.chain().name()
+    }
+
+    /// 按显示名查找（如结果文件中的 `TRON`）
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::all().find(|chain| chain.label() == label)
+    }
+
+    /// 地址可能出现的字符
+    pub fn alphabet(self) -> &'static str {
+        self.chain().alphabet()
+    }
+
+    /// 地址固定的开头字符，前缀 / 位置匹配从其后开始计算
+    pub fn address_prefix(self) -> &'static str {
+        self.chain().address_prefix()
     }
 }
 
@@ -40,564 +149,5996 @@ impl std::fmt::Display for ChainType {
     }
 }
 
-/// 通用的靓号结果结构
-#[derive(Clone, Debug)]
-pub struct VanityAddress {
-    pub chain: ChainType,
-    pub address: String,
-    pub public_key: String,
-    pub private_key: String,
-    pub mnemonic: String,
-}
+/// 一条链的实现：派生路径、私钥到地址、地址字母表与显示名
+///
+/// 新增链时实现此 trait 并加入 [`CHAIN_REGISTRY`]，CLI、GUI 与文件输出都按注册表遍历。
+pub trait Chain: Send + Sync {
+    fn chain_type(&self) -> ChainType;
 
-/// 多链地址结构：一个助记词对应的三种链地址
-#[derive(Clone, Debug)]
-pub struct MultiChainAddress {
-    pub mnemonic: String,
-    pub tron: VanityAddress,
-    pub evm: VanityAddress,
-    pub sol: VanityAddress,
-}
+    /// 显示名
+    fn name(&self) -> &'static str;
 
-/// 从助记词派生种子（BIP39）
-fn mnemonic_to_seed(mnemonic: &str, password: &str) -> [u8; 64] {
-    let parsed = Mnemonic::parse(mnemonic).expect("invalid mnemonic");
-    parsed.to_seed(password)
-}
+    /// 地址可能出现的字符
+    fn alphabet(&self) -> &'static str;
 
-/// 从种子派生 secp256k1 私钥（BIP44 标准派生）
-fn derive_private_key_from_seed_bip44(seed: &[u8; 64], coin_type: u32) -> [u8; 32] {
-    use bip32::{DerivationPath, XPrv};
-    use core::str::FromStr;
+    /// 地址固定的开头字符
+    fn address_prefix(&self) -> &'static str;
+
+    /// BIP44 coin type
+    fn coin_type(&self) -> u32;
+
+    /// 私钥所在的曲线，默认 secp256k1
+    fn curve(&self) -> KeyCurve {
+        KeyCurve::Secp256k1
+    }
+
+    /// 地址去掉固定开头后的长度（用于难度估算与位置匹配校验）
+    fn address_body_len(&self) -> usize;
+
+    /// 字母大小写是否由校验和决定（如 EIP-55），是则按不区分大小写的符号估算概率
+    fn checksum_case(&self) -> bool {
+        false
+    }
+
+    /// 固定开头之后第一位可能出现的字符，None 表示与其它位相同
+    fn leading_chars(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// 固定开头之后第一位为 c 的概率，只在 [`Chain::leading_chars`] 返回 Some 时使用
+    fn leading_char_probability(&self, _c: char, _case_sensitive: bool) -> f64 {
+        0.0
+    }
+
+    /// 派生路径预设，第一个为默认
+    fn path_presets(&self) -> &'static [PathPreset];
 
-    let path = DerivationPath::from_str(&format!("m/44'/{}'/0'/0/0", coin_type))
-        .expect("valid path");
+    /// 默认预设下指定账户与地址索引的派生路径
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        render_path(self.path_presets()[0].template, account, index)
+    }
+
+    /// 检查展开后的路径能否用于该链，默认按 BIP32 解析
+    fn validate_path(&self, path: &str) -> Result<(), VanityError> {
+        use core::str::FromStr;
+
+        bip32::DerivationPath::from_str(path)
+            .map(|_| ())
+            .map_err(|_| VanityError::InvalidDerivationPath(path.to_string()))
+    }
+
+    /// 随机私钥，默认为 secp256k1 私钥
+    fn generate_private_key(&self) -> [u8; 32] {
+        generate_private_key()
+    }
+
+    /// 由 32 字节私钥构建地址
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        mnemonic: Option<SecretString>,
+    ) -> Result<VanityAddress, VanityError>;
+
+    /// 由公钥编码地址（secp256k1 为 SEC1 编码，ed25519 为 32 字节）
+    fn address_from_public_key(&self, public_key: &[u8]) -> Result<String, VanityError>;
+
+    /// 由 20 字节账户（Keccak(公钥) 后 20 字节）编码地址，只有账户模型的链支持
+    fn account_to_address(&self, _account: &[u8; 20]) -> Result<String, VanityError> {
+        Err(VanityError::UnsupportedChain(self.chain_type()))
+    }
+
+    /// 解析地址得到 20 字节账户，只有账户模型的链支持
+    fn parse_account(&self, _address: &str) -> Result<[u8; 20], VanityError> {
+        Err(VanityError::UnsupportedChain(self.chain_type()))
+    }
+
+    /// CREATE2 哈希输入的首字节，不支持 CREATE2 的链返回错误
+    fn create2_prefix(&self) -> Result<u8, VanityError> {
+        Err(VanityError::UnsupportedChain(self.chain_type()))
+    }
+
+    /// Keystore V3 文件的 address 字段与文件名中的地址，不支持 Keystore 的链返回 None
+    fn keystore_address(&self, _addr: &VanityAddress) -> Option<String> {
+        None
+    }
 
-    let child = XPrv::derive_from_path(seed, &path).expect("derive child");
-    child.private_key().to_bytes().into()
+    /// 按路径从 BIP39 种子派生地址，默认按 BIP32 派生 secp256k1 私钥
+    fn derive_from_seed(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        mnemonic: Option<SecretString>,
+    ) -> Result<VanityAddress, VanityError> {
+        let child = derive_secp256k1_xprv(seed, path)?;
+        let private_key: [u8; 32] = child.private_key().to_bytes().into();
+        let mut addr = self.address_from_private_key(&private_key, mnemonic)?;
+        addr.derivation_path = Some(path.to_string());
+        Ok(addr)
+    }
 }
 
-/// 从种子派生 TRON 私钥（m/44'/195'/0'/0/0）
-fn derive_tron_private_key(seed: &[u8; 64]) -> [u8; 32] {
-    derive_private_key_from_seed_bip44(seed, 195) // TRON coin type = 195
+/// 私钥所在的曲线，决定批量公钥、HD 扫描与分离密钥的计算方式
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KeyCurve {
+    Secp256k1,
+    Ed25519,
 }
 
-/// 从种子派生 EVM 私钥（m/44'/60'/0'/0/0）
-fn derive_evm_private_key(seed: &[u8; 64]) -> [u8; 32] {
-    derive_private_key_from_seed_bip44(seed, 60) // Ethereum coin type = 60
+/// 已注册的链，顺序与 [`ChainType`] 的声明顺序一致
+pub static CHAIN_REGISTRY: [&dyn Chain; 3] = [&TronChain, &EvmChain, &SolChain];
+
+/// 钱包的派生路径预设
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PathPreset {
+    pub name: &'static str,
+    /// 路径模板，`{account}` / `{index}` 在派生时替换为账户与地址索引
+    pub template: &'static str,
 }
 
-/// 从种子派生 Solana 私钥（Phantom 等钱包默认使用 m/44'/501'/0'）
-fn derive_sol_private_key(seed: &[u8; 64]) -> [u8; 32] {
-    use core::str::FromStr;
+/// 按账户与地址索引展开路径模板
+pub fn render_path(template: &str, account: u32, index: u32) -> String {
+    template
+        .replace("{account}", &account.to_string())
+        .replace("{index}", &index.to_string())
+}
 
-    let path = Ed25519DerivationPath::from_str("m/44'/501'/0'")
-        .expect("valid solana path");
-    let extended = ExtendedSecretKey::from_seed(seed).expect("valid solana seed");
-    let derived = extended.derive(&path).expect("derive sol key");
-    derived.secret_key.to_bytes()
+/// 每条链使用的派生路径模板，未设置的链使用该链的第一个预设
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct DerivationPaths {
+    templates: BTreeMap<ChainType, String>,
 }
 
-/// 生成随机助记词
-pub fn generate_mnemonic() -> String {
-    let mut entropy = [0u8; 16]; // 128 bits = 12 words
-    rand::thread_rng().fill_bytes(&mut entropy);
-    match Mnemonic::from_entropy(&entropy) {
-        Ok(m) => m.to_string(),
-        Err(_) => "unable to generate mnemonic".to_string(),
+impl DerivationPaths {
+    pub fn template(&self, chain: ChainType) -> &str {
+        self.templates
+            .get(&chain)
+            .map_or(chain.chain().path_presets()[0].template, String::as_str)
     }
-}
 
-/// 从私钥生成 secp256k1 公钥（未压缩）
-pub fn private_key_to_public_key(private_key: &[u8; 32]) -> Vec<u8> {
-    use k256::SecretKey;
-    let secret = SecretKey::from_slice(private_key).expect("valid key");
-    secret
-        .public_key()
-        .to_encoded_point(false)
-        .as_bytes()
-        .to_vec()
+    /// 设置路径模板：必须包含 `{index}`，展开后需能被该链解析
+    pub fn set_template(&mut self, chain: ChainType, template: &str) -> Result<(), VanityError> {
+        let template = template.trim();
+        if !template.contains("{index}") {
+            return Err(VanityError::InvalidDerivationPath(format!(
+                "{} (缺少 {{index}} | missing {{index}})",
+                template
+            )));
+        }
+        chain.chain().validate_path(&render_path(template, 0, 0))?;
+        self.templates.insert(chain, template.to_string());
+        Ok(())
+    }
+
+    /// 当前模板对应的预设名，自定义模板返回 None
+    pub fn preset_name(&self, chain: ChainType) -> Option<&'static str> {
+        let template = self.template(chain);
+        chain
+            .chain()
+            .path_presets()
+            .iter()
+            .find(|preset| preset.template == template)
+            .map(|preset| preset.name)
+    }
+
+    /// 展开后的完整路径
+    pub fn path(&self, chain: ChainType, account: u32, index: u32) -> String {
+        render_path(self.template(chain), account, index)
+    }
 }
 
-/// 从公钥生成 TRON 地址
-pub fn public_key_to_tron_address(public_key: &[u8]) -> String {
-    // 1. Keccak256 公钥（跳过 0x04 前缀），取后 20 字节
-    let mut keccak = Keccak::v256();
-    keccak.update(&public_key[1..]);
-    let mut out = [0u8; 32];
-    keccak.finalize(&mut out);
-    let account = &out[12..];
+/// BIP39 助记词长度：每 3 个词对应 32 位熵（含校验位 1 位）
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum WordCount {
+    #[default]
+    Words12,
+    Words15,
+    Words18,
+    Words21,
+    Words24,
+}
 
-    // 2. 添加 Tron 主网前缀 0x41
-    let mut versioned = vec![0x41];
-    versioned.extend_from_slice(account);
+impl WordCount {
+    pub const ALL: [WordCount; 5] = [
+        WordCount::Words12,
+        WordCount::Words15,
+        WordCount::Words18,
+        WordCount::Words21,
+        WordCount::Words24,
+    ];
 
-    // 3. 双 SHA256 取前 4 字节做校验和
-    let mut hasher = Sha256::new();
-    hasher.update(&versioned);
-    let sha256_1 = hasher.finalize();
+    /// 按词数查找，只接受 12/15/18/21/24
+    pub fn from_words(words: usize) -> Option<Self> {
+        Self::ALL.into_iter().find(|count| count.words() == words)
+    }
 
-    let mut hasher = Sha256::new();
-    hasher.update(&sha256_1);
-    let sha256_2 = hasher.finalize();
+    pub fn words(self) -> usize {
+        match self {
+            WordCount::Words12 => 12,
+            WordCount::Words15 => 15,
+            WordCount::Words18 => 18,
+            WordCount::Words21 => 21,
+            WordCount::Words24 => 24,
+        }
+    }
 
-    let checksum = &sha256_2[0..4];
+    /// 熵字节数：12 词 16 字节（128 位）… 24 词 32 字节（256 位）
+    pub fn entropy_bytes(self) -> usize {
+        self.words() * 4 / 3
+    }
+}
 
-    // 4. 拼接并 Base58 编码
-    let mut address_bytes = versioned;
-    address_bytes.extend_from_slice(checksum);
+impl std::fmt::Display for WordCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} 词 | {} words", self.words(), self.words())
+    }
+}
 
-    bs58::encode(&address_bytes).into_string()
+/// BIP39 词表语言
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum MnemonicLanguage {
+    #[default]
+    English,
+    SimplifiedChinese,
+    TraditionalChinese,
+    Japanese,
+    Korean,
+    Spanish,
+    French,
+    Italian,
+    Czech,
 }
 
-/// 将 20 字节地址编码为 EIP-55 校验格式
-fn evm_checksum_address(address_bytes: &[u8]) -> String {
-    let hex_lower = hex::encode(address_bytes);
-    let mut keccak = Keccak::v256();
-    keccak.update(hex_lower.as_bytes());
-    let mut hash = [0u8; 32];
-    keccak.finalize(&mut hash);
+impl MnemonicLanguage {
+    pub const ALL: [MnemonicLanguage; 9] = [
+        MnemonicLanguage::English,
+        MnemonicLanguage::SimplifiedChinese,
+        MnemonicLanguage::TraditionalChinese,
+        MnemonicLanguage::Japanese,
+        MnemonicLanguage::Korean,
+        MnemonicLanguage::Spanish,
+        MnemonicLanguage::French,
+        MnemonicLanguage::Italian,
+        MnemonicLanguage::Czech,
+    ];
 
-    let mut result = String::with_capacity(42);
-    result.push_str("0x");
+    pub fn label(self) -> &'static str {
+        match self {
+            MnemonicLanguage::English => "English",
+            MnemonicLanguage::SimplifiedChinese => "简体中文",
+            MnemonicLanguage::TraditionalChinese => "繁體中文",
+            MnemonicLanguage::Japanese => "日本語",
+            MnemonicLanguage::Korean => "한국어",
+            MnemonicLanguage::Spanish => "Español",
+            MnemonicLanguage::French => "Français",
+            MnemonicLanguage::Italian => "Italiano",
+            MnemonicLanguage::Czech => "Čeština",
+        }
+    }
 
-    for (i, ch) in hex_lower.chars().enumerate() {
-        let hash_byte = hash[i / 2];
-        let hash_nibble = if i % 2 == 0 {
-            (hash_byte >> 4) & 0x0f
-        } else {
-            hash_byte & 0x0f
-        };
+    /// 写入结果文件的语言标识（与 BIP39 词表文件名一致）
+    pub fn code(self) -> &'static str {
+        match self {
+            MnemonicLanguage::English => "english",
+            MnemonicLanguage::SimplifiedChinese => "chinese-simplified",
+            MnemonicLanguage::TraditionalChinese => "chinese-traditional",
+            MnemonicLanguage::Japanese => "japanese",
+            MnemonicLanguage::Korean => "korean",
+            MnemonicLanguage::Spanish => "spanish",
+            MnemonicLanguage::French => "french",
+            MnemonicLanguage::Italian => "italian",
+            MnemonicLanguage::Czech => "czech",
+        }
+    }
 
-        if ch.is_ascii_alphabetic() && hash_nibble >= 8 {
-            result.push(ch.to_ascii_uppercase());
-        } else {
-            result.push(ch.to_ascii_lowercase());
+    /// 按 [`MnemonicLanguage::code`] 查找
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code.trim())
+    }
+
+    fn to_bip39(self) -> bip39::Language {
+        match self {
+            MnemonicLanguage::English => bip39::Language::English,
+            MnemonicLanguage::SimplifiedChinese => bip39::Language::SimplifiedChinese,
+            MnemonicLanguage::TraditionalChinese => bip39::Language::TraditionalChinese,
+            MnemonicLanguage::Japanese => bip39::Language::Japanese,
+            MnemonicLanguage::Korean => bip39::Language::Korean,
+            MnemonicLanguage::Spanish => bip39::Language::Spanish,
+            MnemonicLanguage::French => bip39::Language::French,
+            MnemonicLanguage::Italian => bip39::Language::Italian,
+            MnemonicLanguage::Czech => bip39::Language::Czech,
         }
     }
 
-    result
+    fn from_bip39(language: bip39::Language) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.to_bip39() == language)
+            .unwrap_or_default()
+    }
 }
 
-/// 生成 TRON 地址
-pub fn generate_tron_address() -> VanityAddress {
-    // 1. 先生成助记词
-    let mnemonic = generate_mnemonic();
-    
-    // 2. 从助记词派生种子
-    let seed = mnemonic_to_seed(&mnemonic, "");
-    
-    // 3. 从种子派生 TRON 私钥 (BIP44 m/44'/195'/0'/0/0)
-    let private_key = derive_tron_private_key(&seed);
-    
-    // 4. 从私钥生成公钥和地址
-    let public_key = private_key_to_public_key(&private_key);
-    let address = public_key_to_tron_address(&public_key);
-
-    VanityAddress {
-        chain: ChainType::Tron,
-        address,
-        public_key: hex::encode(&public_key),
-        private_key: hex::encode(&private_key),
-        mnemonic,
+impl std::fmt::Display for MnemonicLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
     }
 }
 
-/// 生成 EVM 地址（以太坊兼容）
-pub fn generate_evm_address() -> VanityAddress {
-    // 1. 先生成助记词
-    let mnemonic = generate_mnemonic();
-    
-    // 2. 从助记词派生种子
-    let seed = mnemonic_to_seed(&mnemonic, "");
-    
-    // 3. 从种子派生 EVM 私钥 (BIP44 m/44'/60'/0'/0/0)
-    let private_key = derive_evm_private_key(&seed);
-    
-    // 4. 从私钥生成公钥和地址
-    let public_key = private_key_to_public_key(&private_key);
+/// 助记词选项：语言、长度、派生路径与 BIP39 密码（“第 25 个词”）
+#[derive(Debug, Clone, Default)]
+pub struct MnemonicOptions {
+    pub language: MnemonicLanguage,
+    pub word_count: WordCount,
+    pub paths: DerivationPaths,
+    /// BIP39 密码，None 与空字符串等价
+    pub passphrase: Option<SecretString>,
+}
 
-    // keccak256 公钥（去掉 0x04 前缀）后取后 20 字节
-    let mut keccak = Keccak::v256();
-    keccak.update(&public_key[1..]);
-    let mut out = [0u8; 32];
-    keccak.finalize(&mut out);
-    let address_bytes = &out[12..];
-    let address = evm_checksum_address(address_bytes);
+impl MnemonicOptions {
+    /// 参与 PBKDF2 的密码明文，未设置时为空字符串
+    fn passphrase(&self) -> &str {
+        self.passphrase
+            .as_ref()
+            .map_or("", SecretString::expose_secret)
+    }
 
-    VanityAddress {
-        chain: ChainType::Evm,
-        address,
-        public_key: hex::encode(&public_key),
-        private_key: hex::encode(&private_key),
-        mnemonic,
+    /// 是否使用了非空密码（结果中只记录这一点，不记录密码本身）
+    pub fn uses_passphrase(&self) -> bool {
+        !self.passphrase().is_empty()
     }
 }
 
-/// 生成 Solana 地址
-pub fn generate_sol_address() -> VanityAddress {
-    // 1. 先生成助记词
-    let mnemonic = generate_mnemonic();
-    
-    // 2. 从助记词派生种子
-    let seed_64 = mnemonic_to_seed(&mnemonic, "");
-    
-    // 3. 从种子派生 Solana 私钥 (BIP44 m/44'/501'/0'/0/0)
-    let seed = derive_sol_private_key(&seed_64);
+/// TRON：secp256k1 公钥的 Keccak 账户加 0x41 前缀，Base58Check 编码
+struct TronChain;
 
-    let secret = SecretKey::from_bytes(&seed).expect("valid seed");
-    let public: PublicKey = (&secret).into();
+impl Chain for TronChain {
+    fn chain_type(&self) -> ChainType {
+        ChainType::Tron
+    }
 
-    let address = bs58::encode(public.as_bytes()).into_string();
+    fn name(&self) -> &'static str {
+        "TRON"
+    }
 
-    VanityAddress {
-        chain: ChainType::Sol,
-        address,
-        public_key: hex::encode(public.as_bytes()),
-        private_key: hex::encode(seed),
-        mnemonic,
+    fn alphabet(&self) -> &'static str {
+        BASE58_ALPHABET
     }
-}
 
-/// 从单个助记词生成所有三种链的地址
-pub fn generate_from_mnemonic_all(mnemonic: &str) -> MultiChainAddress {
-    let seed = mnemonic_to_seed(mnemonic, "");
-    
-    // TRON
-    let tron_priv = derive_tron_private_key(&seed);
-    let tron_pub = private_key_to_public_key(&tron_priv);
-    let tron_addr = public_key_to_tron_address(&tron_pub);
-    let tron = VanityAddress {
-        chain: ChainType::Tron,
-        address: tron_addr,
-        public_key: hex::encode(&tron_pub),
-        private_key: hex::encode(&tron_priv),
-        mnemonic: mnemonic.to_string(),
-    };
-    
-    // EVM
-    let evm_priv = derive_evm_private_key(&seed);
-    let evm_pub = private_key_to_public_key(&evm_priv);
-    let mut keccak = Keccak::v256();
-    keccak.update(&evm_pub[1..]);
-    let mut out = [0u8; 32];
-    keccak.finalize(&mut out);
-    let evm_addr = evm_checksum_address(&out[12..]);
-    let evm = VanityAddress {
-        chain: ChainType::Evm,
-        address: evm_addr,
-        public_key: hex::encode(&evm_pub),
-        private_key: hex::encode(&evm_priv),
-        mnemonic: mnemonic.to_string(),
-    };
-    
-    // Solana
-    let sol_priv = derive_sol_private_key(&seed);
-    let sol_secret = SecretKey::from_bytes(&sol_priv).expect("valid seed");
-    let sol_pub: PublicKey = (&sol_secret).into();
-    let sol_addr = bs58::encode(sol_pub.as_bytes()).into_string();
-    let sol = VanityAddress {
-        chain: ChainType::Sol,
-        address: sol_addr,
-        public_key: hex::encode(sol_pub.as_bytes()),
-        private_key: hex::encode(&sol_priv),
-        mnemonic: mnemonic.to_string(),
-    };
-    
-    MultiChainAddress {
-        mnemonic: mnemonic.to_string(),
-        tron,
-        evm,
-        sol,
+    fn address_prefix(&self) -> &'static str {
+        "T"
     }
-}
 
-/// 按链类型生成地址
-pub fn generate_vanity_address(chain: ChainType) -> VanityAddress {
-    match chain {
-        ChainType::Tron => generate_tron_address(),
-        ChainType::Evm => generate_evm_address(),
-        ChainType::Sol => generate_sol_address(),
+    fn coin_type(&self) -> u32 {
+        195
     }
-}
 
-/// 检查是否为靓号：末尾匹配模式或末尾连续 >=3 相同字符
-pub fn is_vanity_address(address: &str, patterns: &[&str]) -> bool {
-    let address_lower = address.to_lowercase();
+    fn address_body_len(&self) -> usize {
+        33
+    }
 
-    if !patterns.is_empty() {
-        for pattern in patterns {
-            if address_lower.ends_with(&pattern.to_lowercase()) {
-                return true;
-            }
-        }
-        return false;
+    fn path_presets(&self) -> &'static [PathPreset] {
+        &[
+            PathPreset {
+                name: "TronLink",
+                template: "m/44'/195'/{account}'/0/{index}",
+            },
+            PathPreset {
+                name: "Ledger Live",
+                template: "m/44'/195'/{index}'/0/0",
+            },
+        ]
     }
 
-    let chars: Vec<char> = address_lower.chars().collect();
-    if chars.len() < 3 {
-        return false;
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        mnemonic: Option<SecretString>,
+    ) -> Result<VanityAddress, VanityError> {
+        tron_address_from_private_key(private_key, mnemonic)
     }
 
-    let mut consecutive = 1;
-    for i in (1..chars.len()).rev() {
-        if chars[i] == chars[i - 1] && chars[i].is_ascii_alphanumeric() {
-            consecutive += 1;
-            if consecutive >= 3 {
-                return true;
-            }
+    fn address_from_public_key(&self, public_key: &[u8]) -> Result<String, VanityError> {
+        public_key_to_tron_address(public_key)
+    }
+
+    fn account_to_address(&self, account: &[u8; 20]) -> Result<String, VanityError> {
+        Ok(account_to_tron_address(account))
+    }
+
+    /// Base58Check（校验 0x41 前缀与校验和）或 41 开头的 42 位十六进制
+    fn parse_account(&self, address: &str) -> Result<[u8; 20], VanityError> {
+        let invalid = || VanityError::InvalidAddress(address.to_string());
+        let bytes = if address.len() == 42 && address.starts_with("41") {
+            hex::decode(&address[2..]).map_err(|_| invalid())?
         } else {
-            break;
-        }
+            let decoded = bs58::decode(address).into_vec().map_err(|_| invalid())?;
+            if decoded.len() != 25
+                || decoded[0] != 0x41
+                || tron_checksum(&decoded[..21]) != decoded[21..]
+            {
+                return Err(invalid());
+            }
+            decoded[1..21].to_vec()
+        };
+        bytes.try_into().map_err(|_| invalid())
     }
 
-    false
-}
+    fn leading_chars(&self) -> Option<&'static str> {
+        Some(tron_leading_chars())
+    }
 
-/// 打印到控制台
-pub fn print_address(addr: &VanityAddress, is_vanity: bool) {
-    use colored::*;
+    fn leading_char_probability(&self, c: char, case_sensitive: bool) -> f64 {
+        tron_leading_char_probability(c, case_sensitive)
+    }
 
-    if is_vanity {
-        println!(
-            "{}",
-            "╔════════════════════════════════════════════════════════════╗".bright_yellow()
-        );
-        println!(
-            "{} {}",
-            "║ 发现靓号! | Found Vanity Address! |".bright_yellow(),
-            "".bright_yellow()
-        );
-        println!(
-            "{}",
-            "╠════════════════════════════════════════════════════════════╣".bright_yellow()
-        );
-        println!("{} {}", "链 | Chain:".bright_green(), addr.chain.label());
-        println!(
-            "{} {}",
-            "地址 | Address:".bright_green(),
-            addr.address.bright_cyan()
-        );
-        println!(
-            "{} {}",
-            "私钥 | Private Key:".bright_red(),
-            addr.private_key.bright_white()
-        );
-        println!("{} {}", "公钥 | Public Key:".bright_blue(), addr.public_key);
-        println!(
-            "{} {}",
-            "助记词 | Mnemonic:".bright_magenta(),
-            addr.mnemonic
-        );
-        println!(
-            "{}",
-            "╚════════════════════════════════════════════════════════════╝".bright_yellow()
-        );
-    } else {
-        println!("{} {}", "地址 | Address:".bright_green(), addr.address);
+    /// TRON 的 CREATE2 使用地址版本字节 0x41 作为前缀
+    fn create2_prefix(&self) -> Result<u8, VanityError> {
+        Ok(0x41)
+    }
+
+    /// 与 TronLink / wallet-cli 一致，写 Base58 地址
+    fn keystore_address(&self, addr: &VanityAddress) -> Option<String> {
+        Some(addr.address.clone())
     }
 }
 
-/// 打印多链地址到控制台（命中任意链时，列出三条链）
-pub fn print_multi_address(multi: &MultiChainAddress, hit_chain: ChainType) {
-    use colored::*;
+/// EVM：secp256k1 公钥的 Keccak 账户，EIP-55 校验格式
+struct EvmChain;
 
-    println!(
-        "{}",
-        "╔════════════════════════════════════════════════════════════╗".bright_yellow()
-    );
-    println!(
-        "{} {}",
-        "║ 发现靓号! | Found Vanity Address! |".bright_yellow(),
-        "".bright_yellow()
-    );
-    println!(
-        "{}",
-        "╠════════════════════════════════════════════════════════════╣".bright_yellow()
-    );
-    println!(
-        "{} {}",
-        "命中链 | Hit Chain:".bright_green(),
-        hit_chain.label()
-    );
+impl Chain for EvmChain {
+    fn chain_type(&self) -> ChainType {
+        ChainType::Evm
+    }
+
+    fn name(&self) -> &'static str {
+        "EVM"
+    }
+
+    fn alphabet(&self) -> &'static str {
+        "0123456789abcdefABCDEF"
+    }
+
+    fn address_prefix(&self) -> &'static str {
+        "0x"
+    }
+
+    fn coin_type(&self) -> u32 {
+        60
+    }
+
+    fn address_body_len(&self) -> usize {
+        40
+    }
+
+    fn path_presets(&self) -> &'static [PathPreset] {
+        &[
+            PathPreset {
+                name: "MetaMask",
+                template: "m/44'/60'/{account}'/0/{index}",
+            },
+            PathPreset {
+                name: "Ledger Live",
+                template: "m/44'/60'/{index}'/0/0",
+            },
+            PathPreset {
+                name: "Ledger Legacy / MEW",
+                template: "m/44'/60'/0'/{index}",
+            },
+        ]
+    }
+
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        mnemonic: Option<SecretString>,
+    ) -> Result<VanityAddress, VanityError> {
+        evm_address_from_private_key(private_key, mnemonic)
+    }
+
+    fn address_from_public_key(&self, public_key: &[u8]) -> Result<String, VanityError> {
+        public_key_to_evm_address(public_key)
+    }
+
+    fn account_to_address(&self, account: &[u8; 20]) -> Result<String, VanityError> {
+        Ok(evm_checksum_address(account))
+    }
+
+    /// 0x 开头（可省略）的 40 位十六进制
+    fn parse_account(&self, address: &str) -> Result<[u8; 20], VanityError> {
+        let invalid = || VanityError::InvalidAddress(address.to_string());
+        let hex_part = address.strip_prefix("0x").unwrap_or(address);
+        hex::decode(hex_part)
+            .map_err(|_| invalid())?
+            .try_into()
+            .map_err(|_| invalid())
+    }
+
+    fn checksum_case(&self) -> bool {
+        true
+    }
+
+    fn create2_prefix(&self) -> Result<u8, VanityError> {
+        Ok(0xff)
+    }
+
+    /// 与 geth 一致，写不带 0x 的小写十六进制
+    fn keystore_address(&self, addr: &VanityAddress) -> Option<String> {
+        Some(addr.address.trim_start_matches("0x").to_lowercase())
+    }
+}
+
+/// Solana：ed25519 公钥直接 Base58 编码，SLIP-0010 只支持硬化派生
+struct SolChain;
+
+impl Chain for SolChain {
+    fn chain_type(&self) -> ChainType {
+        ChainType::Sol
+    }
+
+    fn name(&self) -> &'static str {
+        "SOL"
+    }
+
+    fn alphabet(&self) -> &'static str {
+        BASE58_ALPHABET
+    }
+
+    fn address_prefix(&self) -> &'static str {
+        ""
+    }
+
+    fn coin_type(&self) -> u32 {
+        501
+    }
+
+    fn curve(&self) -> KeyCurve {
+        KeyCurve::Ed25519
+    }
+
+    /// Solana 地址长度不固定，按常见的 44 计
+    fn address_body_len(&self) -> usize {
+        44
+    }
+
+    fn path_presets(&self) -> &'static [PathPreset] {
+        &[
+            PathPreset {
+                name: "Ledger Live / Trust",
+                template: "m/44'/501'/{index}'",
+            },
+            PathPreset {
+                name: "Phantom / Solflare",
+                template: "m/44'/501'/{index}'/0'",
+            },
+            PathPreset {
+                name: "solana-cli",
+                template: "m/44'/501'/{account}'/{index}'",
+            },
+        ]
+    }
+
+    /// SLIP-0010 ed25519 只支持硬化派生
+    fn validate_path(&self, path: &str) -> Result<(), VanityError> {
+        use core::str::FromStr;
+
+        let all_hardened = path
+            .split('/')
+            .skip(1)
+            .all(|component| component.ends_with('\''));
+        Ed25519DerivationPath::from_str(path)
+            .ok()
+            .filter(|_| all_hardened)
+            .map(|_| ())
+            .ok_or_else(|| VanityError::InvalidDerivationPath(path.to_string()))
+    }
+
+    /// 任意 32 字节都是合法的 ed25519 种子
+    fn generate_private_key(&self) -> [u8; 32] {
+        let mut seed = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed);
+        seed
+    }
+
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        mnemonic: Option<SecretString>,
+    ) -> Result<VanityAddress, VanityError> {
+        sol_address_from_seed(private_key, mnemonic)
+    }
+
+    fn address_from_public_key(&self, public_key: &[u8]) -> Result<String, VanityError> {
+        if public_key.len() != 32 {
+            return Err(VanityError::InvalidPublicKey);
+        }
+        Ok(bs58::encode(public_key).into_string())
+    }
+
+    fn derive_from_seed(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        mnemonic: Option<SecretString>,
+    ) -> Result<VanityAddress, VanityError> {
+        let mut addr = sol_address_from_seed(&derive_ed25519_secret(seed, path)?, mnemonic)?;
+        addr.derivation_path = Some(path.to_string());
+        Ok(addr)
+    }
+}
+
+/// 搜索模式
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SearchMode {
+    /// 助记词模式：BIP39 助记词 → PBKDF2 种子 → BIP44 派生，可用助记词恢复
+    Mnemonic,
+    /// 裸私钥模式：直接随机 32 字节私钥，跳过 BIP39/PBKDF2，没有助记词备份
+    RawKey,
+    /// 递增模式：随机起点 k 后依次尝试 k+1, k+2, …，每步只做一次点加（仅 TRON/EVM，SOL 退化为裸私钥）
+    Incremental,
+    /// HD 索引扫描：一个助记词只做一次 PBKDF2，然后扫描多个 BIP44 地址索引，命中可用助记词 + 路径恢复
+    HdScan,
+}
+
+impl SearchMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchMode::Mnemonic => "助记词 | Mnemonic",
+            SearchMode::RawKey => "裸私钥 | Raw Key",
+            SearchMode::Incremental => "递增 | Incremental",
+            SearchMode::HdScan => "HD 索引扫描 | HD Scan",
+        }
+    }
+}
+
+impl std::fmt::Display for SearchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// 私钥 / 助记词等敏感字符串
+///
+/// Drop 时清零内存，`Debug` 输出打码；明文只能通过 [`SecretString::expose_secret`] 显式取出。
+#[derive(Clone, Eq, PartialEq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: String) -> Self {
+        Self(secret)
+    }
+
+    /// 取出明文（仅用于展示、保存或重新派生）
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretString([REDACTED])")
+    }
+}
+
+/// 通用的靓号结果结构
+#[derive(Clone, Debug)]
+pub struct VanityAddress {
+    pub chain: ChainType,
+    pub address: String,
+    pub public_key: String,
+    pub private_key: SecretString,
+    /// 裸私钥模式下为 None，只能通过私钥恢复
+    pub mnemonic: Option<SecretString>,
+    /// 助记词派生时使用的完整路径，如 m/44'/195'/0'/0/7
+    pub derivation_path: Option<String>,
+    /// 派生时使用了 BIP39 密码；恢复时除助记词外还需要该密码
+    pub passphrase_used: bool,
+    /// 助记词的词表语言（无助记词时无意义）
+    pub mnemonic_language: MnemonicLanguage,
+}
+
+/// HD 索引扫描范围：每个助记词扫描账户 0..accounts、地址索引 0..indices
+///
+/// 路径由 [`DerivationPaths`] 的模板展开（默认 TRON/EVM 为 m/44'/{coin}'/{account}'/0/{index}）；
+/// 模板不含 `{account}` 的链（如 SOL 默认的 m/44'/501'/{index}'）只在账户 0 上扫描。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct HdScanRange {
+    pub accounts: u32,
+    pub indices: u32,
+}
+
+impl Default for HdScanRange {
+    fn default() -> Self {
+        Self {
+            accounts: 1,
+            indices: 1000,
+        }
+    }
+}
+
+/// 多链地址结构：一个助记词在注册表各链上派生的地址
+#[derive(Clone, Debug)]
+pub struct MultiChainAddress {
+    pub mnemonic: SecretString,
+    /// 按链索引的派生结果，遍历顺序即注册顺序
+    pub addresses: BTreeMap<ChainType, VanityAddress>,
+    /// 派生时使用了 BIP39 密码
+    pub passphrase_used: bool,
+    pub language: MnemonicLanguage,
+}
+
+impl MultiChainAddress {
+    pub fn get(&self, chain: ChainType) -> Option<&VanityAddress> {
+        self.addresses.get(&chain)
+    }
+}
+
+/// 解析任意已支持语言的助记词
+///
+/// 简体与繁体中文词表有大量相同的字，只由共同字组成的助记词两种语言都合法，
+/// 此时按 [`MnemonicLanguage::ALL`] 的顺序取第一个能通过校验的语言（种子只取决于文字本身，结果相同）。
+fn parse_mnemonic(mnemonic: &str) -> Result<Mnemonic, VanityError> {
+    match Mnemonic::parse(mnemonic) {
+        Err(bip39::Error::AmbiguousLanguages(languages)) => languages
+            .iter()
+            .find_map(|language| Mnemonic::parse_in(language, mnemonic).ok())
+            .ok_or_else(|| {
+                VanityError::InvalidMnemonic(
+                    bip39::Error::AmbiguousLanguages(languages).to_string(),
+                )
+            }),
+        parsed => parsed.map_err(|e| VanityError::InvalidMnemonic(e.to_string())),
+    }
+}
+
+/// 识别助记词的词表语言
+pub fn mnemonic_language(mnemonic: &str) -> Result<MnemonicLanguage, VanityError> {
+    Ok(MnemonicLanguage::from_bip39(
+        parse_mnemonic(mnemonic)?.language(),
+    ))
+}
+
+/// 从助记词派生种子（BIP39）
+fn mnemonic_to_seed(mnemonic: &str, password: &str) -> Result<[u8; 64], VanityError> {
+    Ok(parse_mnemonic(mnemonic)?.to_seed(password))
+}
+
+/// 按路径从种子派生 secp256k1 扩展私钥（BIP32）
+fn derive_secp256k1_xprv(seed: &[u8; 64], path: &str) -> Result<bip32::XPrv, VanityError> {
+    use bip32::{DerivationPath, XPrv};
+    use core::str::FromStr;
+
+    let path = DerivationPath::from_str(path)
+        .map_err(|_| VanityError::InvalidDerivationPath(path.to_string()))?;
+    XPrv::derive_from_path(seed, &path).map_err(|e| VanityError::Derivation(e.to_string()))
+}
+
+/// 按路径从种子派生 ed25519 扩展私钥（SLIP-0010，仅支持硬化路径）
+fn derive_ed25519_extended(seed: &[u8; 64], path: &str) -> Result<ExtendedSecretKey, VanityError> {
+    use core::str::FromStr;
+
+    let parsed = Ed25519DerivationPath::from_str(path)
+        .map_err(|_| VanityError::InvalidDerivationPath(path.to_string()))?;
+    ExtendedSecretKey::from_seed(seed)
+        .and_then(|extended| extended.derive(&parsed))
+        .map_err(|e| VanityError::Derivation(e.to_string()))
+}
+
+/// 按路径从种子派生 ed25519 私钥
+fn derive_ed25519_secret(seed: &[u8; 64], path: &str) -> Result<[u8; 32], VanityError> {
+    Ok(derive_ed25519_extended(seed, path)?.secret_key.to_bytes())
+}
+
+/// 32 字节私钥转换为 secp256k1 标量，拒绝 0 和超出曲线阶的值
+fn secp256k1_scalar(private_key: &[u8; 32]) -> Result<Scalar, VanityError> {
+    Option::<Scalar>::from(Scalar::from_repr((*private_key).into()))
+        .filter(|scalar| !bool::from(scalar.is_zero()))
+        .ok_or(VanityError::InvalidPrivateKey)
+}
+
+/// 默认预设下指定账户与地址索引的 BIP44 派生路径
+pub fn bip44_path(chain: ChainType, account: u32, index: u32) -> String {
+    chain.chain().derivation_path(account, index)
+}
+
+/// secp256k1 BIP32 父节点（如 m/44'/coin'/account'/0），用于快速派生大量非硬化子私钥
+struct Bip32Parent {
+    private_key: Scalar,
+    public_key: [u8; 33],
+    chain_code: [u8; 32],
+}
+
+impl Bip32Parent {
+    fn from_path(seed: &[u8; 64], path: &str) -> Result<Self, VanityError> {
+        let parent = derive_secp256k1_xprv(seed, path)?;
+        let private_key: [u8; 32] = parent.private_key().to_bytes().into();
+
+        Ok(Self {
+            private_key: secp256k1_scalar(&private_key)?,
+            public_key: parent.public_key().to_bytes(),
+            chain_code: parent.attrs().chain_code,
+        })
+    }
+
+    /// BIP32 CKDpriv（非硬化）：k_i = k_par + IL，IL = HMAC-SHA512(c_par, K_par || i) 前 32 字节
+    ///
+    /// 父公钥只算一次，每个子私钥只需一次 HMAC 和一次标量加法。
+    fn derive_child(&self, index: u32) -> Option<[u8; 32]> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(&self.chain_code).ok()?;
+        mac.update(&self.public_key);
+        mac.update(&index.to_be_bytes());
+        let output = mac.finalize().into_bytes();
+
+        let mut tweak = [0u8; 32];
+        tweak.copy_from_slice(&output[..32]);
+        let tweak = Option::<Scalar>::from(Scalar::from_repr(tweak.into()))?;
+        let child = self.private_key + tweak;
+        if bool::from(child.is_zero()) {
+            return None;
+        }
+        Some(child.to_bytes().into())
+    }
+}
+
+/// 生成随机助记词（英文 12 词）
+pub fn generate_mnemonic() -> Result<String, VanityError> {
+    generate_mnemonic_with_words(WordCount::default())
+}
+
+/// 生成指定长度的随机英文助记词
+pub fn generate_mnemonic_with_words(word_count: WordCount) -> Result<String, VanityError> {
+    generate_mnemonic_in(MnemonicLanguage::English, word_count)
+}
+
+/// 生成指定语言与长度的随机助记词
+pub fn generate_mnemonic_in(
+    language: MnemonicLanguage,
+    word_count: WordCount,
+) -> Result<String, VanityError> {
+    let mut entropy = [0u8; 32];
+    let entropy = &mut entropy[..word_count.entropy_bytes()];
+    rand::thread_rng().fill_bytes(entropy);
+    let mnemonic = Mnemonic::from_entropy_in(language.to_bip39(), entropy)
+        .map(|m| m.to_string())
+        .map_err(|e| VanityError::InvalidEntropy(e.to_string()));
+    entropy.zeroize();
+    mnemonic
+}
+
+/// 生成随机 secp256k1 私钥（32 字节，保证落在曲线阶范围内）
+pub fn generate_private_key() -> [u8; 32] {
+    let mut key = [0u8; 32];
+    loop {
+        rand::thread_rng().fill_bytes(&mut key);
+        if k256::SecretKey::from_slice(&key).is_ok() {
+            return key;
+        }
+    }
+}
+
+/// 从私钥生成 secp256k1 公钥（未压缩）
+pub fn private_key_to_public_key(private_key: &[u8; 32]) -> Result<Vec<u8>, VanityError> {
+    use k256::SecretKey;
+    let secret = SecretKey::from_slice(private_key).map_err(|_| VanityError::InvalidPrivateKey)?;
+    Ok(secret
+        .public_key()
+        .to_encoded_point(false)
+        .as_bytes()
+        .to_vec())
+}
+
+/// secp256k1 递增私钥游走器：从起点 k 开始依次产出 (k, k·G), (k+1, (k+1)·G), …
+///
+/// 每一步只在上一个公钥上加一次生成元 G，避免 `private_key_to_public_key`
+/// 中的完整标量乘法。产出的私钥与公钥严格对应，可直接用于导入钱包。
+#[derive(Clone, Debug)]
+pub struct Secp256k1KeyWalker {
+    scalar: Scalar,
+    point: ProjectivePoint,
+}
+
+impl Secp256k1KeyWalker {
+    /// 从指定私钥开始游走；私钥为 0 或超出曲线阶时返回错误
+    pub fn new(start: &[u8; 32]) -> Result<Self, VanityError> {
+        let scalar = secp256k1_scalar(start)?;
+        Ok(Self {
+            scalar,
+            point: ProjectivePoint::GENERATOR * scalar,
+        })
+    }
+
+    /// 从随机私钥开始游走
+    pub fn random() -> Self {
+        let scalar = Scalar::generate_vartime(&mut rand::thread_rng());
+        Self {
+            scalar,
+            point: ProjectivePoint::GENERATOR * scalar,
+        }
+    }
+
+    /// 下一次将产出的私钥
+    pub fn current_private_key(&self) -> [u8; 32] {
+        self.scalar.to_bytes().into()
+    }
+
+    /// 一次取出 count 个连续私钥及其公钥
+    ///
+    /// 先在射影坐标下连续点加，再用 `normalize_public_keys` 统一转换为仿射坐标，
+    /// 整批只做一次域求逆。
+    pub fn next_batch(&mut self, count: usize) -> Vec<([u8; 32], Vec<u8>)> {
+        let mut private_keys = Vec::with_capacity(count);
+        let mut points = Vec::with_capacity(count);
+        for _ in 0..count {
+            private_keys.push(self.current_private_key());
+            points.push(self.point);
+            self.advance();
+        }
+        private_keys
+            .into_iter()
+            .zip(normalize_public_keys(&points))
+            .collect()
+    }
+
+    /// 前进一步：k += 1，P += G（跨过阶回绕到 0 的极端情况）
+    fn advance(&mut self) {
+        self.scalar += Scalar::ONE;
+        self.point += ProjectivePoint::GENERATOR;
+        if bool::from(self.scalar.is_zero()) {
+            self.scalar = Scalar::ONE;
+            self.point = ProjectivePoint::GENERATOR;
+        }
+    }
+}
+
+impl Iterator for Secp256k1KeyWalker {
+    /// (私钥, 未压缩公钥)
+    type Item = ([u8; 32], Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let private_key = self.current_private_key();
+        let public_key = self
+            .point
+            .to_affine()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        self.advance();
+        Some((private_key, public_key))
+    }
+}
+
+/// 把一批射影坐标点转换为未压缩 SEC1 公钥
+///
+/// 使用 Montgomery 批量求逆（k256 `BatchNormalize`）：N 个点的 Z 坐标共用一次域求逆，
+/// 而逐个调用 `to_affine` 需要 N 次求逆。
+fn normalize_public_keys(points: &[ProjectivePoint]) -> Vec<Vec<u8>> {
+    <ProjectivePoint as BatchNormalize<[ProjectivePoint]>>::batch_normalize(points)
+        .iter()
+        .map(|affine| affine.to_encoded_point(false).as_bytes().to_vec())
+        .collect()
+}
+
+/// 批量从私钥生成 secp256k1 公钥（未压缩），整批只做一次域求逆
+pub fn batch_private_keys_to_public_keys(
+    private_keys: &[[u8; 32]],
+) -> Result<Vec<Vec<u8>>, VanityError> {
+    let points = private_keys
+        .iter()
+        .map(|key| Ok(ProjectivePoint::GENERATOR * secp256k1_scalar(key)?))
+        .collect::<Result<Vec<_>, VanityError>>()?;
+    Ok(normalize_public_keys(&points))
+}
+
+/// Keccak256 公钥（跳过 0x04 前缀），取后 20 字节作为账户地址
+pub fn public_key_to_account(public_key: &[u8]) -> Result<[u8; 20], VanityError> {
+    if public_key.len() != 65 || public_key[0] != 0x04 {
+        return Err(VanityError::InvalidPublicKey);
+    }
+    Ok(keccak_account(&public_key[1..]))
+}
+
+/// Keccak256 摘要
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    keccak.update(data);
+    let mut out = [0u8; 32];
+    keccak.finalize(&mut out);
+    out
+}
+
+/// Keccak256 后取后 20 字节
+fn keccak_account(data: &[u8]) -> [u8; 20] {
+    let out = keccak256(data);
+    let mut account = [0u8; 20];
+    account.copy_from_slice(&out[12..]);
+    account
+}
+
+/// 从公钥生成 TRON 地址
+pub fn public_key_to_tron_address(public_key: &[u8]) -> Result<String, VanityError> {
+    // 1. Keccak256 公钥（跳过 0x04 前缀），取后 20 字节
+    Ok(account_to_tron_address(&public_key_to_account(public_key)?))
+}
+
+/// 由 20 字节账户地址编码 TRON Base58Check 地址
+pub fn account_to_tron_address(account: &[u8]) -> String {
+    // 2. 添加 Tron 主网前缀 0x41
+    let mut versioned = vec![0x41];
+    versioned.extend_from_slice(account);
+
+    // 3. 双 SHA256 取前 4 字节做校验和
+    let checksum = tron_checksum(&versioned);
+
+    // 4. 拼接并 Base58 编码
+    let mut address_bytes = versioned;
+    address_bytes.extend_from_slice(&checksum);
+
+    bs58::encode(&address_bytes).into_string()
+}
+
+/// Base58Check 校验和：双 SHA256 的前 4 字节
+fn tron_checksum(versioned: &[u8]) -> [u8; 4] {
+    let sha256_2 = Sha256::digest(Sha256::digest(versioned));
+    [sha256_2[0], sha256_2[1], sha256_2[2], sha256_2[3]]
+}
+
+/// 解析地址得到 20 字节账户：EVM 为 0x 开头的 40 位十六进制；
+/// TRON 为 Base58Check（校验 0x41 前缀与校验和）或 41 开头的 42 位十六进制
+pub fn parse_account(chain: ChainType, address: &str) -> Result<[u8; 20], VanityError> {
+    chain.chain().parse_account(address.trim())
+}
+
+/// 从公钥生成 EVM 地址（EIP-55 校验格式）
+pub fn public_key_to_evm_address(public_key: &[u8]) -> Result<String, VanityError> {
+    // keccak256 公钥（去掉 0x04 前缀）后取后 20 字节
+    Ok(evm_checksum_address(&public_key_to_account(public_key)?))
+}
+
+/// 将 20 字节地址编码为 EIP-55 校验格式
+fn evm_checksum_address(address_bytes: &[u8]) -> String {
+    let hex_lower = hex::encode(address_bytes);
+    let mut keccak = Keccak::v256();
+    keccak.update(hex_lower.as_bytes());
+    let mut hash = [0u8; 32];
+    keccak.finalize(&mut hash);
+
+    let mut result = String::with_capacity(42);
+    result.push_str("0x");
+
+    for (i, ch) in hex_lower.chars().enumerate() {
+        let hash_byte = hash[i / 2];
+        let hash_nibble = if i % 2 == 0 {
+            (hash_byte >> 4) & 0x0f
+        } else {
+            hash_byte & 0x0f
+        };
+
+        if ch.is_ascii_alphabetic() && hash_nibble >= 8 {
+            result.push(ch.to_ascii_uppercase());
+        } else {
+            result.push(ch.to_ascii_lowercase());
+        }
+    }
+
+    result
+}
+
+/// 由已知的 secp256k1 私钥和公钥构建 TRON/EVM 结果（不再做标量乘法）
+fn secp256k1_address_from_keys(
+    chain: ChainType,
+    private_key: &[u8; 32],
+    public_key: &[u8],
+    mnemonic: Option<SecretString>,
+) -> Result<VanityAddress, VanityError> {
+    let account = public_key_to_account(public_key)?;
+    secp256k1_address_from_account(chain, private_key, public_key, &account, mnemonic)
+}
+
+/// 由已算好的账户哈希构建 TRON/EVM 结果
+fn secp256k1_address_from_account(
+    chain: ChainType,
+    private_key: &[u8; 32],
+    public_key: &[u8],
+    account: &[u8; 20],
+    mnemonic: Option<SecretString>,
+) -> Result<VanityAddress, VanityError> {
+    Ok(VanityAddress {
+        chain,
+        address: chain.chain().account_to_address(account)?,
+        public_key: hex::encode(public_key),
+        private_key: hex::encode(private_key).into(),
+        mnemonic,
+        derivation_path: None,
+        passphrase_used: false,
+        mnemonic_language: MnemonicLanguage::English,
+    })
+}
+
+/// 由 secp256k1 私钥构建 TRON 结果
+fn tron_address_from_private_key(
+    private_key: &[u8; 32],
+    mnemonic: Option<SecretString>,
+) -> Result<VanityAddress, VanityError> {
+    let public_key = private_key_to_public_key(private_key)?;
+    secp256k1_address_from_keys(ChainType::Tron, private_key, &public_key, mnemonic)
+}
+
+/// 由 secp256k1 私钥构建 EVM 结果
+fn evm_address_from_private_key(
+    private_key: &[u8; 32],
+    mnemonic: Option<SecretString>,
+) -> Result<VanityAddress, VanityError> {
+    let public_key = private_key_to_public_key(private_key)?;
+    secp256k1_address_from_keys(ChainType::Evm, private_key, &public_key, mnemonic)
+}
+
+/// 由 ed25519 种子构建 Solana 结果
+fn sol_address_from_seed(
+    seed: &[u8; 32],
+    mnemonic: Option<SecretString>,
+) -> Result<VanityAddress, VanityError> {
+    let secret = SecretKey::from_bytes(seed).map_err(|_| VanityError::InvalidPrivateKey)?;
+    let public: PublicKey = (&secret).into();
+
+    let address = bs58::encode(public.as_bytes()).into_string();
+
+    Ok(VanityAddress {
+        chain: ChainType::Sol,
+        address,
+        public_key: hex::encode(public.as_bytes()),
+        private_key: hex::encode(seed).into(),
+        mnemonic,
+        derivation_path: None,
+        passphrase_used: false,
+        mnemonic_language: MnemonicLanguage::English,
+    })
+}
+
+/// 从种子按所选路径派生指定链的地址（账户 0、地址索引 0）
+fn derive_address_from_seed(
+    seed: &[u8; 64],
+    chain: ChainType,
+    mnemonic: &str,
+    options: &MnemonicOptions,
+) -> Result<VanityAddress, VanityError> {
+    let mut addr = chain.chain().derive_from_seed(
+        seed,
+        &options.paths.path(chain, 0, 0),
+        Some(mnemonic.into()),
+    )?;
+    addr.passphrase_used = options.uses_passphrase();
+    addr.mnemonic_language = options.language;
+    Ok(addr)
+}
+
+/// 从助记词（及 BIP39 密码，无则传空字符串）派生指定账户与地址索引的地址（用于恢复 HD 扫描命中的结果）
+pub fn derive_address_at(
+    mnemonic: &str,
+    passphrase: &str,
+    chain: ChainType,
+    account: u32,
+    index: u32,
+) -> Result<VanityAddress, VanityError> {
+    derive_address_from_path(
+        mnemonic,
+        passphrase,
+        chain,
+        &bip44_path(chain, account, index),
+    )
+}
+
+/// 从助记词（及 BIP39 密码，无则传空字符串）按完整路径派生地址（用于按命中记录中的路径恢复）
+pub fn derive_address_from_path(
+    mnemonic: &str,
+    passphrase: &str,
+    chain: ChainType,
+    path: &str,
+) -> Result<VanityAddress, VanityError> {
+    let parsed = parse_mnemonic(mnemonic)?;
+    let seed = parsed.to_seed(passphrase);
+    let mut addr = chain
+        .chain()
+        .derive_from_seed(&seed, path, Some(mnemonic.into()))?;
+    addr.passphrase_used = !passphrase.is_empty();
+    addr.mnemonic_language = MnemonicLanguage::from_bip39(parsed.language());
+    Ok(addr)
+}
+
+/// 解析账户 / 地址索引范围：`5` 表示 5..6，`0-9` 表示 0..10，留空使用默认值
+pub fn parse_index_range(
+    input: &str,
+    default: std::ops::Range<u32>,
+) -> Option<std::ops::Range<u32>> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Some(default);
+    }
+    let (start, end) = match input.split_once('-') {
+        Some((start, end)) => (start.trim().parse().ok()?, end.trim().parse::<u32>().ok()?),
+        None => {
+            let index = input.parse().ok()?;
+            (index, index)
+        }
+    };
+    (start <= end && end < 1 << 31).then(|| start..end + 1)
+}
+
+/// 从已有助记词按各链的路径模板批量派生地址（用于审计旧钱包）
+///
+/// 每条链依次派生账户 `accounts`、地址索引 `indices` 范围内的所有路径，PBKDF2 只算一次；
+/// 模板不含 `{account}` 的链只派生一遍，避免重复。
+pub fn derive_addresses(
+    mnemonic: &str,
+    options: &MnemonicOptions,
+    chains: &[ChainType],
+    accounts: std::ops::Range<u32>,
+    indices: std::ops::Range<u32>,
+) -> Result<Vec<VanityAddress>, VanityError> {
+    let parsed = parse_mnemonic(mnemonic)?;
+    let seed = parsed.to_seed(options.passphrase());
+    let language = MnemonicLanguage::from_bip39(parsed.language());
+
+    let mut addresses = Vec::new();
+    for &chain in chains {
+        let template = options.paths.template(chain);
+        let accounts = if template.contains("{account}") {
+            accounts.clone()
+        } else {
+            accounts.start..accounts.end.min(accounts.start + 1)
+        };
+        for account in accounts {
+            for index in indices.clone() {
+                let path = options.paths.path(chain, account, index);
+                let mut addr =
+                    chain
+                        .chain()
+                        .derive_from_seed(&seed, &path, Some(mnemonic.into()))?;
+                addr.passphrase_used = options.uses_passphrase();
+                addr.mnemonic_language = language;
+                addresses.push(addr);
+            }
+        }
+    }
+    Ok(addresses)
+}
+
+/// 生成 TRON 地址
+pub fn generate_tron_address() -> Result<VanityAddress, VanityError> {
+    // 1. 先生成助记词
+    let mnemonic = generate_mnemonic()?;
+
+    // 2. 从助记词派生种子
+    let seed = mnemonic_to_seed(&mnemonic, "")?;
+
+    // 3. 从种子派生 TRON 私钥 (BIP44 m/44'/195'/0'/0/0)，再生成公钥和地址
+    derive_address_from_seed(
+        &seed,
+        ChainType::Tron,
+        &mnemonic,
+        &MnemonicOptions::default(),
+    )
+}
+
+/// 生成 EVM 地址（以太坊兼容）
+pub fn generate_evm_address() -> Result<VanityAddress, VanityError> {
+    // 1. 先生成助记词
+    let mnemonic = generate_mnemonic()?;
+
+    // 2. 从助记词派生种子
+    let seed = mnemonic_to_seed(&mnemonic, "")?;
+
+    // 3. 从种子派生 EVM 私钥 (BIP44 m/44'/60'/0'/0/0)，再生成公钥和地址
+    derive_address_from_seed(
+        &seed,
+        ChainType::Evm,
+        &mnemonic,
+        &MnemonicOptions::default(),
+    )
+}
+
+/// 生成 Solana 地址
+pub fn generate_sol_address() -> Result<VanityAddress, VanityError> {
+    // 1. 先生成助记词
+    let mnemonic = generate_mnemonic()?;
+
+    // 2. 从助记词派生种子
+    let seed = mnemonic_to_seed(&mnemonic, "")?;
+
+    // 3. 从种子派生 Solana 私钥 (m/44'/501'/0')，再生成公钥和地址
+    derive_address_from_seed(
+        &seed,
+        ChainType::Sol,
+        &mnemonic,
+        &MnemonicOptions::default(),
+    )
+}
+
+/// 裸私钥模式生成地址（无助记词）
+pub fn generate_raw_address(chain: ChainType) -> Result<VanityAddress, VanityError> {
+    let chain = chain.chain();
+    chain.address_from_private_key(&chain.generate_private_key(), None)
+}
+
+/// 裸私钥模式生成 TRON 地址（无助记词）
+pub fn generate_raw_tron_address() -> Result<VanityAddress, VanityError> {
+    generate_raw_address(ChainType::Tron)
+}
+
+/// 裸私钥模式生成 EVM 地址（无助记词）
+pub fn generate_raw_evm_address() -> Result<VanityAddress, VanityError> {
+    generate_raw_address(ChainType::Evm)
+}
+
+/// 裸私钥模式生成 Solana 地址（无助记词，任意 32 字节都是合法 ed25519 种子）
+pub fn generate_raw_sol_address() -> Result<VanityAddress, VanityError> {
+    generate_raw_address(ChainType::Sol)
+}
+
+/// 从单个助记词按默认路径生成注册表中所有链的地址（无 BIP39 密码）
+pub fn generate_from_mnemonic_all(mnemonic: &str) -> Result<MultiChainAddress, VanityError> {
+    generate_from_mnemonic_with_options(mnemonic, &MnemonicOptions::default())
+}
+
+/// 从单个助记词按所选路径与 BIP39 密码生成注册表中所有链的地址（语言按助记词本身识别）
+pub fn generate_from_mnemonic_with_options(
+    mnemonic: &str,
+    options: &MnemonicOptions,
+) -> Result<MultiChainAddress, VanityError> {
+    let parsed = parse_mnemonic(mnemonic)?;
+    let seed = parsed.to_seed(options.passphrase());
+    let options = MnemonicOptions {
+        language: MnemonicLanguage::from_bip39(parsed.language()),
+        ..options.clone()
+    };
+
+    Ok(MultiChainAddress {
+        mnemonic: mnemonic.into(),
+        addresses: ChainType::all()
+            .map(|chain| {
+                Ok((
+                    chain,
+                    derive_address_from_seed(&seed, chain, mnemonic, &options)?,
+                ))
+            })
+            .collect::<Result<_, VanityError>>()?,
+        passphrase_used: options.uses_passphrase(),
+        language: options.language,
+    })
+}
+
+/// 按链类型生成地址（助记词模式）
+pub fn generate_vanity_address(chain: ChainType) -> Result<VanityAddress, VanityError> {
+    let mnemonic = generate_mnemonic()?;
+    let seed = mnemonic_to_seed(&mnemonic, "")?;
+    derive_address_from_seed(&seed, chain, &mnemonic, &MnemonicOptions::default())
+}
+
+/// 按链类型和搜索模式生成地址（递增模式需要保存状态，单次生成时等同裸私钥）
+pub fn generate_vanity_address_with_mode(
+    chain: ChainType,
+    mode: SearchMode,
+) -> Result<VanityAddress, VanityError> {
+    match mode {
+        SearchMode::Mnemonic => generate_vanity_address(chain),
+        _ => generate_raw_address(chain),
+    }
+}
+
+/// 生成一次尝试的候选地址：
+/// 助记词模式下一个助记词派生所有指定链（PBKDF2 只算一次），
+/// 裸私钥模式下每条链各取一个随机私钥
+pub fn generate_candidates(
+    mode: SearchMode,
+    chains: &[ChainType],
+) -> Result<Vec<VanityAddress>, VanityError> {
+    match mode {
+        SearchMode::Mnemonic => mnemonic_candidates(chains, &MnemonicOptions::default()),
+        SearchMode::RawKey | SearchMode::Incremental | SearchMode::HdScan => chains
+            .iter()
+            .map(|&chain| generate_vanity_address_with_mode(chain, mode))
+            .collect(),
+    }
+}
+
+/// 助记词模式的一次尝试：一个新助记词按所选路径与密码派生所有指定链
+fn mnemonic_candidates(
+    chains: &[ChainType],
+    options: &MnemonicOptions,
+) -> Result<Vec<VanityAddress>, VanityError> {
+    let mnemonic = generate_mnemonic_in(options.language, options.word_count)?;
+    let seed = mnemonic_to_seed(&mnemonic, options.passphrase())?;
+    chains
+        .iter()
+        .map(|&chain| derive_address_from_seed(&seed, chain, &mnemonic, options))
+        .collect()
+}
+
+/// HD 扫描时某条链在当前账户下的派生方式
+enum ScanParent {
+    /// 模板末级为非硬化 `{index}`：父节点只派生一次，每个索引一次 CKDpriv
+    Secp256k1(Bip32Parent),
+    /// 模板末级为硬化 `{index}'`（ed25519）：父节点只派生一次
+    Ed25519(ExtendedSecretKey),
+    /// 其他模板（如 Ledger Live 的 `{index}` 在账户层）：每个索引完整派生
+    Full,
+}
+
+/// HD 索引扫描的当前助记词状态
+struct HdScanState {
+    mnemonic: SecretString,
+    seed: [u8; 64],
+    passphrase_used: bool,
+    language: MnemonicLanguage,
+    account: u32,
+    index: u32,
+    /// 当前账户下各链的父节点
+    parents: Vec<(ChainType, ScanParent)>,
+}
+
+impl HdScanState {
+    fn new(chains: &[ChainType], options: &MnemonicOptions) -> Result<Self, VanityError> {
+        let mnemonic = generate_mnemonic_in(options.language, options.word_count)?;
+        let seed = mnemonic_to_seed(&mnemonic, options.passphrase())?;
+        let mut state = Self {
+            mnemonic: mnemonic.into(),
+            seed,
+            passphrase_used: options.uses_passphrase(),
+            language: options.language,
+            account: 0,
+            index: 0,
+            parents: Vec::new(),
+        };
+        state.load_account(chains, &options.paths, 0)?;
+        Ok(state)
+    }
+
+    /// 切换到指定账户；模板不含 `{account}` 的链只在账户 0 上扫描
+    fn load_account(
+        &mut self,
+        chains: &[ChainType],
+        paths: &DerivationPaths,
+        account: u32,
+    ) -> Result<(), VanityError> {
+        self.account = account;
+        self.index = 0;
+        self.parents = Vec::with_capacity(chains.len());
+
+        for &chain in chains {
+            let template = paths.template(chain);
+            if account > 0 && !template.contains("{account}") {
+                continue;
+            }
+            let rendered = template.replace("{account}", &account.to_string());
+            let parent = match (rendered.rsplit_once('/'), chain.chain().curve()) {
+                (Some((parent_path, "{index}")), KeyCurve::Secp256k1) => {
+                    ScanParent::Secp256k1(Bip32Parent::from_path(&self.seed, parent_path)?)
+                }
+                (Some((parent_path, "{index}'")), KeyCurve::Ed25519) => {
+                    ScanParent::Ed25519(derive_ed25519_extended(&self.seed, parent_path)?)
+                }
+                _ => ScanParent::Full,
+            };
+            self.parents.push((chain, parent));
+        }
+        Ok(())
+    }
+
+    /// 派生当前位置的候选地址（概率低于 2^-127 的无效子密钥直接跳过）
+    fn candidates(&self, paths: &DerivationPaths) -> Result<Vec<VanityAddress>, VanityError> {
+        let mnemonic = Some(self.mnemonic.clone());
+        let mut candidates = Vec::with_capacity(self.parents.len());
+
+        for (chain, parent) in &self.parents {
+            let chain = *chain;
+            let path = paths.path(chain, self.account, self.index);
+            let mut addr = match parent {
+                ScanParent::Secp256k1(parent) => {
+                    let Some(private_key) = parent.derive_child(self.index) else {
+                        continue;
+                    };
+                    let public_key = private_key_to_public_key(&private_key)?;
+                    secp256k1_address_from_keys(chain, &private_key, &public_key, mnemonic.clone())?
+                }
+                ScanParent::Ed25519(parent) => {
+                    let index = ChildIndex::hardened(self.index)
+                        .map_err(|e| VanityError::Derivation(e.to_string()))?;
+                    let Ok(child) = parent.derive_child(index) else {
+                        continue;
+                    };
+                    chain
+                        .chain()
+                        .address_from_private_key(&child.secret_key.to_bytes(), mnemonic.clone())?
+                }
+                ScanParent::Full => {
+                    chain
+                        .chain()
+                        .derive_from_seed(&self.seed, &path, mnemonic.clone())?
+                }
+            };
+            addr.derivation_path = Some(path);
+            addr.passphrase_used = self.passphrase_used;
+            addr.mnemonic_language = self.language;
+            candidates.push(addr);
+        }
+
+        Ok(candidates)
+    }
+}
+
+/// secp256k1 (私钥, 未压缩公钥)
+type Secp256k1KeyPair = ([u8; 32], Vec<u8>);
+
+/// 每个工作线程持有一个候选生成器，保存递增模式等需要跨次调用的状态
+pub struct CandidateGenerator {
+    mode: SearchMode,
+    chains: Vec<ChainType>,
+    walker: Option<Secp256k1KeyWalker>,
+    hd_range: HdScanRange,
+    hd_state: Option<HdScanState>,
+    options: MnemonicOptions,
+}
+
+impl CandidateGenerator {
+    pub fn new(mode: SearchMode, chains: &[ChainType]) -> Self {
+        let walker = (mode == SearchMode::Incremental).then(Secp256k1KeyWalker::random);
+        Self {
+            mode,
+            chains: chains.to_vec(),
+            walker,
+            hd_range: HdScanRange::default(),
+            hd_state: None,
+            options: MnemonicOptions::default(),
+        }
+    }
+
+    pub fn mode(&self) -> SearchMode {
+        self.mode
+    }
+
+    /// 设置 HD 索引扫描范围（仅 HdScan 模式生效），从下一个助记词开始应用
+    pub fn set_hd_range(&mut self, range: HdScanRange) {
+        self.hd_range = HdScanRange {
+            accounts: range.accounts.max(1),
+            indices: range.indices.clamp(1, 1 << 31),
+        };
+    }
+
+    /// 设置助记词 / HD 扫描模式的派生路径与 BIP39 密码，从下一个助记词开始应用
+    pub fn set_mnemonic_options(&mut self, options: MnemonicOptions) {
+        self.options = options;
+        self.hd_state = None;
+    }
+
+    /// HD 扫描的下一个位置：扫完当前账户的索引后换账户，扫完所有账户后换新助记词
+    fn next_hd_candidates(&mut self) -> Result<Vec<VanityAddress>, VanityError> {
+        let range = self.hd_range;
+        let state = match self.hd_state.as_mut() {
+            Some(state) if state.index < range.indices => state,
+            Some(state) if state.account + 1 < range.accounts => {
+                let account = state.account + 1;
+                state.load_account(&self.chains, &self.options.paths, account)?;
+                state
+            }
+            _ => self
+                .hd_state
+                .insert(HdScanState::new(&self.chains, &self.options)?),
+        };
+
+        let candidates = state.candidates(&self.options.paths)?;
+        state.index += 1;
+        Ok(candidates)
+    }
+
+    /// 生成一次尝试的候选地址（语义同 `generate_candidates`）
+    ///
+    /// 递增模式下 TRON 与 EVM 共用同一个 secp256k1 私钥，SOL 仍使用随机裸私钥。
+    pub fn next_candidates(&mut self) -> Result<Vec<VanityAddress>, VanityError> {
+        Ok(self.next_batch(1)?.pop().unwrap_or_default())
+    }
+
+    /// 生成 batch_size 次尝试的候选地址，batch_size 即一次批量密码学运算的规模：
+    /// 裸私钥/递增模式下整批公钥共用一次域求逆，随后对整批公钥做 Keccak
+    pub fn next_batch(
+        &mut self,
+        batch_size: usize,
+    ) -> Result<Vec<Vec<VanityAddress>>, VanityError> {
+        match self.mode {
+            SearchMode::Mnemonic => {
+                return (0..batch_size)
+                    .map(|_| mnemonic_candidates(&self.chains, &self.options))
+                    .collect();
+            }
+            SearchMode::HdScan => {
+                return (0..batch_size).map(|_| self.next_hd_candidates()).collect();
+            }
+            SearchMode::RawKey | SearchMode::Incremental => {}
+        }
+
+        // 只选了 ed25519 链时不必计算 secp256k1 公钥与 Keccak
+        if self
+            .chains
+            .iter()
+            .all(|chain| chain.chain().curve() != KeyCurve::Secp256k1)
+        {
+            return (0..batch_size)
+                .map(|_| {
+                    self.chains
+                        .iter()
+                        .map(|&chain| generate_raw_address(chain))
+                        .collect()
+                })
+                .collect();
+        }
+
+        let keys = self.next_secp256k1_keys(batch_size)?;
+        keys.iter()
+            .map(|(private_key, public_key)| {
+                let account = public_key_to_account(public_key)?;
+                self.chains
+                    .iter()
+                    .map(|&chain| match chain.chain().curve() {
+                        KeyCurve::Secp256k1 => secp256k1_address_from_account(
+                            chain,
+                            private_key,
+                            public_key,
+                            &account,
+                            None,
+                        ),
+                        KeyCurve::Ed25519 => generate_raw_address(chain),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// 裸私钥 / 递增模式下取 batch_size 个 secp256k1 私钥及公钥，整批共用一次域求逆
+    fn next_secp256k1_keys(
+        &mut self,
+        batch_size: usize,
+    ) -> Result<Vec<Secp256k1KeyPair>, VanityError> {
+        match self.walker.as_mut() {
+            Some(walker) => Ok(walker.next_batch(batch_size)),
+            None => {
+                let private_keys: Vec<[u8; 32]> =
+                    (0..batch_size).map(|_| generate_private_key()).collect();
+                let public_keys = batch_private_keys_to_public_keys(&private_keys)?;
+                Ok(private_keys.into_iter().zip(public_keys).collect())
+            }
+        }
+    }
+
+    /// EVM 前导零搜索：生成 batch_size 个 EVM 候选，返回本批前导零最多的一个
+    ///
+    /// 裸私钥 / 递增模式直接在 Keccak 输出的 20 字节账户（`out[12..]`）上计数，
+    /// 只对本批最好的一个做 EIP-55 编码；助记词 / HD 扫描模式的派生开销远大于编码，按已编码地址计数。
+    pub fn next_leading_zero_batch(
+        &mut self,
+        batch_size: usize,
+        unit: ZeroUnit,
+    ) -> Result<Option<LeadingZeroHit>, VanityError> {
+        if matches!(self.mode, SearchMode::Mnemonic | SearchMode::HdScan) {
+            let best = self
+                .next_batch(batch_size)?
+                .into_iter()
+                .flatten()
+                .filter(|addr| addr.chain == ChainType::Evm)
+                .map(|addr| {
+                    let account =
+                        hex::decode(addr.address.trim_start_matches("0x")).unwrap_or_default();
+                    LeadingZeroHit {
+                        zeros: count_leading_zeros(&account, unit),
+                        address: addr,
+                    }
+                })
+                .max_by_key(|hit| hit.zeros);
+            return Ok(best);
+        }
+
+        let keys = self.next_secp256k1_keys(batch_size)?;
+        let mut best: Option<(u32, usize, [u8; 20])> = None;
+        for (i, (_, public_key)) in keys.iter().enumerate() {
+            let account = public_key_to_account(public_key)?;
+            let zeros = count_leading_zeros(&account, unit);
+            if best.is_none_or(|(best_zeros, ..)| zeros > best_zeros) {
+                best = Some((zeros, i, account));
+            }
+        }
+
+        best.map(|(zeros, i, account)| {
+            let (private_key, public_key) = &keys[i];
+            Ok(LeadingZeroHit {
+                zeros,
+                address: secp256k1_address_from_account(
+                    ChainType::Evm,
+                    private_key,
+                    public_key,
+                    &account,
+                    None,
+                )?,
+            })
+        })
+        .transpose()
+    }
+
+    /// CREATE 合约地址搜索：对本批每个 EVM 账户预测 nonce 0..=max_nonce 的合约地址，返回匹配的结果
+    ///
+    /// 裸私钥 / 递增模式只对命中的账户做 EIP-55 编码；助记词 / HD 扫描模式按已编码地址解析账户。
+    pub fn next_contract_batch(
+        &mut self,
+        batch_size: usize,
+        max_nonce: u64,
+        matcher: &PatternMatcher,
+    ) -> Result<Vec<ContractHit>, VanityError> {
+        let find = |account: &[u8; 20]| {
+            (0..=max_nonce).find_map(|nonce| {
+                let contract = evm_checksum_address(&create_contract_account(account, nonce));
+                matcher
+                    .is_match(ChainType::Evm, &contract)
+                    .then_some((nonce, contract))
+            })
+        };
+
+        let mut hits = Vec::new();
+        if matches!(self.mode, SearchMode::Mnemonic | SearchMode::HdScan) {
+            for addr in self.next_batch(batch_size)?.into_iter().flatten() {
+                if addr.chain != ChainType::Evm {
+                    continue;
+                }
+                if let Some((nonce, contract_address)) =
+                    find(&parse_account(ChainType::Evm, &addr.address)?)
+                {
+                    hits.push(ContractHit {
+                        nonce,
+                        contract_address,
+                        deployer: addr,
+                    });
+                }
+            }
+            return Ok(hits);
+        }
+
+        for (private_key, public_key) in self.next_secp256k1_keys(batch_size)? {
+            let account = public_key_to_account(&public_key)?;
+            if let Some((nonce, contract_address)) = find(&account) {
+                hits.push(ContractHit {
+                    nonce,
+                    contract_address,
+                    deployer: secp256k1_address_from_account(
+                        ChainType::Evm,
+                        &private_key,
+                        &public_key,
+                        &account,
+                        None,
+                    )?,
+                });
+            }
+        }
+        Ok(hits)
+    }
+}
+
+/// RLP 编码 [sender, nonce]（CREATE 合约地址的哈希输入）
+pub fn rlp_encode_create(sender: &[u8; 20], nonce: u64) -> Vec<u8> {
+    // nonce 为去掉前导零的大端字节；0 编码为空字符串 0x80，单字节 < 0x80 直接编码
+    let nonce_bytes = nonce.to_be_bytes();
+    let nonce_bytes = &nonce_bytes[nonce.leading_zeros() as usize / 8..];
+    let mut payload = Vec::with_capacity(30);
+    payload.push(0x80 + 20);
+    payload.extend_from_slice(sender);
+    match nonce_bytes {
+        [byte] if *byte < 0x80 => payload.push(*byte),
+        _ => {
+            payload.push(0x80 + nonce_bytes.len() as u8);
+            payload.extend_from_slice(nonce_bytes);
+        }
+    }
+
+    // 列表载荷最长 30 字节，始终是短列表
+    let mut encoded = Vec::with_capacity(payload.len() + 1);
+    encoded.push(0xc0 + payload.len() as u8);
+    encoded.extend_from_slice(&payload);
+    encoded
+}
+
+/// CREATE 合约账户：keccak256(rlp([sender, nonce]))[12..]
+pub fn create_contract_account(sender: &[u8; 20], nonce: u64) -> [u8; 20] {
+    keccak_account(&rlp_encode_create(sender, nonce))
+}
+
+/// 预测 EVM 账户以 nonce 部署的合约地址（EIP-55 校验格式）
+pub fn create_contract_address(sender: &[u8; 20], nonce: u64) -> String {
+    evm_checksum_address(&create_contract_account(sender, nonce))
+}
+
+/// CREATE 合约地址命中：部署账户（EOA）的完整密钥、nonce 与合约地址
+#[derive(Clone, Debug)]
+pub struct ContractHit {
+    pub nonce: u64,
+    pub contract_address: String,
+    pub deployer: VanityAddress,
+}
+
+/// EVM 前导零的计数单位
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ZeroUnit {
+    /// 半字节：地址 0x 之后每个 0 字符
+    Nibble,
+    /// 整字节：两个 0 字符，calldata 中的零字节 gas 更低（4 vs 16）
+    Byte,
+}
+
+impl ZeroUnit {
+    pub fn label(self) -> &'static str {
+        match self {
+            ZeroUnit::Nibble => "半字节 | nibbles",
+            ZeroUnit::Byte => "字节 | bytes",
+        }
+    }
+
+    /// 单次尝试至少得到 target 个前导零的难度
+    pub fn difficulty(self, target: u32) -> Difficulty {
+        let base: f64 = match self {
+            ZeroUnit::Nibble => 16.0,
+            ZeroUnit::Byte => 256.0,
+        };
+        Difficulty {
+            probability: base.powi(-(target as i32)),
+        }
+    }
+}
+
+/// 统计账户（Keccak 输出的 20 字节）开头的 0 个数
+pub fn count_leading_zeros(account: &[u8], unit: ZeroUnit) -> u32 {
+    let mut bits = 0;
+    for &byte in account {
+        bits += byte.leading_zeros();
+        if byte != 0 {
+            break;
+        }
+    }
+    match unit {
+        ZeroUnit::Nibble => bits / 4,
+        ZeroUnit::Byte => bits / 8,
+    }
+}
+
+/// 前导零搜索的一个结果
+#[derive(Clone, Debug)]
+pub struct LeadingZeroHit {
+    pub zeros: u32,
+    pub address: VanityAddress,
+}
+
+/// 前导零搜索进度：记录迄今最好的结果，达到目标个数后结束
+#[derive(Clone, Debug)]
+pub struct LeadingZeroSearch {
+    pub unit: ZeroUnit,
+    pub target: u32,
+    best: Option<LeadingZeroHit>,
+}
+
+impl LeadingZeroSearch {
+    pub fn new(unit: ZeroUnit, target: u32) -> Self {
+        Self {
+            unit,
+            target,
+            best: None,
+        }
+    }
+
+    /// 提交一个结果，比当前最好的结果前导零更多时替换并返回 true
+    pub fn offer(&mut self, hit: LeadingZeroHit) -> bool {
+        if self
+            .best
+            .as_ref()
+            .is_some_and(|best| best.zeros >= hit.zeros)
+        {
+            return false;
+        }
+        self.best = Some(hit);
+        true
+    }
+
+    pub fn best(&self) -> Option<&LeadingZeroHit> {
+        self.best.as_ref()
+    }
+
+    /// 是否已达到目标个数
+    pub fn is_complete(&self) -> bool {
+        self.best
+            .as_ref()
+            .is_some_and(|best| best.zeros >= self.target)
+    }
+}
+
+/// 单线程短时测速：用一个临时生成器持续生成 duration，返回每秒候选数（用于开始前估算耗时）
+pub fn measure_rate(
+    mode: SearchMode,
+    chains: &[ChainType],
+    hd_range: HdScanRange,
+    duration: std::time::Duration,
+) -> Result<f64, VanityError> {
+    let mut generator = CandidateGenerator::new(mode, chains);
+    generator.set_hd_range(hd_range);
+
+    let start = std::time::Instant::now();
+    let mut count = 0usize;
+    while start.elapsed() < duration {
+        count += generator.next_batch(16)?.len();
+    }
+    Ok(count as f64 / start.elapsed().as_secs_f64())
+}
+
+/// 分离密钥（外包搜索）请求：请求方保留私钥，只把公钥交给搜索方
+#[derive(Clone, Debug)]
+pub struct SplitKeyRequest {
+    pub chain: ChainType,
+    /// 请求方私钥（hex，必须自行保密）
+    pub private_key: SecretString,
+    /// 交给搜索方的公钥（secp256k1 为未压缩 SEC1 hex，ed25519 为 32 字节 hex）
+    pub public_key: String,
+}
+
+/// 分离密钥搜索结果：只包含搜索方的部分私钥，拿不到最终私钥
+#[derive(Clone, Debug)]
+pub struct SplitKeyResult {
+    pub chain: ChainType,
+    /// 合成后的地址
+    pub address: String,
+    /// 合成后的公钥（hex）
+    pub public_key: String,
+    /// 搜索方找到的部分私钥（hex），需与请求方私钥相加才得到最终私钥
+    pub partial_private_key: String,
+}
+
+/// 请求方公钥（已解析，搜索时复用）
+#[derive(Clone, Debug)]
+enum SplitKeyBase {
+    Secp256k1(ProjectivePoint),
+    Ed25519(EdwardsPoint),
+}
+
+/// 分离密钥搜索方：反复随机部分私钥 b，检查 请求方公钥 + b·G 对应的地址
+#[derive(Clone, Debug)]
+pub struct SplitKeyWorker {
+    chain: ChainType,
+    base: SplitKeyBase,
+}
+
+/// 生成分离密钥请求（请求方执行）
+pub fn generate_split_key_request(chain: ChainType) -> Result<SplitKeyRequest, VanityError> {
+    match chain.chain().curve() {
+        KeyCurve::Ed25519 => {
+            let scalar = random_ed25519_scalar();
+            let public_key = &scalar * &ED25519_BASEPOINT_TABLE;
+            Ok(SplitKeyRequest {
+                chain,
+                private_key: hex::encode(scalar.as_bytes()).into(),
+                public_key: hex::encode(public_key.compress().as_bytes()),
+            })
+        }
+        KeyCurve::Secp256k1 => {
+            let private_key = generate_private_key();
+            Ok(SplitKeyRequest {
+                chain,
+                private_key: hex::encode(private_key).into(),
+                public_key: hex::encode(private_key_to_public_key(&private_key)?),
+            })
+        }
+    }
+}
+
+impl SplitKeyWorker {
+    /// 解析请求方公钥：TRON/EVM 接受压缩或未压缩 SEC1，SOL 接受 32 字节公钥
+    pub fn new(chain: ChainType, requester_public_key: &[u8]) -> Result<Self, VanityError> {
+        let base = match chain.chain().curve() {
+            KeyCurve::Ed25519 => {
+                let bytes: [u8; 32] = requester_public_key
+                    .try_into()
+                    .map_err(|_| VanityError::InvalidPublicKey)?;
+                let point = CompressedEdwardsY(bytes)
+                    .decompress()
+                    .ok_or(VanityError::InvalidPublicKey)?;
+                SplitKeyBase::Ed25519(point)
+            }
+            KeyCurve::Secp256k1 => {
+                let public_key = k256::PublicKey::from_sec1_bytes(requester_public_key)
+                    .map_err(|_| VanityError::InvalidPublicKey)?;
+                SplitKeyBase::Secp256k1(public_key.to_projective())
+            }
+        };
+        Ok(Self { chain, base })
+    }
+
+    /// 尝试一个随机部分私钥
+    pub fn next_candidate(&self) -> SplitKeyResult {
+        match &self.base {
+            SplitKeyBase::Secp256k1(base) => {
+                let partial = Scalar::generate_vartime(&mut rand::thread_rng());
+                let combined = (*base + ProjectivePoint::GENERATOR * partial).to_affine();
+                let public_key = combined.to_encoded_point(false).as_bytes().to_vec();
+                SplitKeyResult {
+                    chain: self.chain,
+                    address: self
+                        .chain
+                        .chain()
+                        .address_from_public_key(&public_key)
+                        .unwrap_or_default(),
+                    public_key: hex::encode(&public_key),
+                    partial_private_key: hex::encode(partial.to_bytes()),
+                }
+            }
+            SplitKeyBase::Ed25519(base) => {
+                let partial = random_ed25519_scalar();
+                let combined = (base + &partial * &ED25519_BASEPOINT_TABLE).compress();
+                SplitKeyResult {
+                    chain: self.chain,
+                    address: self
+                        .chain
+                        .chain()
+                        .address_from_public_key(combined.as_bytes())
+                        .unwrap_or_default(),
+                    public_key: hex::encode(combined.as_bytes()),
+                    partial_private_key: hex::encode(partial.as_bytes()),
+                }
+            }
+        }
+    }
+}
+
+/// 合成最终私钥（请求方执行）：最终私钥 = 请求方私钥 + 部分私钥（模曲线阶）
+///
+/// TRON/EVM 得到普通 secp256k1 私钥；SOL 得到 ed25519 标量私钥，
+/// 它不是 32 字节种子，需用 `ExpandedSecretKey`（标量 ‖ SHA-512(标量) 后 32 字节）签名。
+pub fn combine_split_key(
+    chain: ChainType,
+    requester_private_key: &[u8; 32],
+    partial_private_key: &[u8; 32],
+) -> Result<VanityAddress, VanityError> {
+    match chain.chain().curve() {
+        KeyCurve::Ed25519 => {
+            let a = Ed25519Scalar::from_canonical_bytes(*requester_private_key)
+                .ok_or(VanityError::InvalidPrivateKey)?;
+            let b = Ed25519Scalar::from_canonical_bytes(*partial_private_key)
+                .ok_or(VanityError::InvalidPrivateKey)?;
+            ed25519_scalar_address(chain, &(a + b))
+        }
+        KeyCurve::Secp256k1 => {
+            let a = secp256k1_scalar(requester_private_key)?;
+            let b = secp256k1_scalar(partial_private_key)?;
+            let private_key: [u8; 32] = (a + b).to_bytes().into();
+            chain.chain().address_from_private_key(&private_key, None)
+        }
+    }
+}
+
+/// 由 ed25519 标量私钥（而非种子）构建地址，private_key 字段为标量
+fn ed25519_scalar_address(
+    chain: ChainType,
+    scalar: &Ed25519Scalar,
+) -> Result<VanityAddress, VanityError> {
+    let public_key = (scalar * &ED25519_BASEPOINT_TABLE).compress();
+    Ok(VanityAddress {
+        chain,
+        address: chain
+            .chain()
+            .address_from_public_key(public_key.as_bytes())?,
+        public_key: hex::encode(public_key.as_bytes()),
+        private_key: hex::encode(scalar.as_bytes()).into(),
+        mnemonic: None,
+        derivation_path: None,
+        passphrase_used: false,
+        mnemonic_language: MnemonicLanguage::English,
+    })
+}
+
+/// ed25519 标量私钥扩展为 64 字节签名密钥：标量 ‖ SHA-512(标量)[32..]
+pub fn ed25519_scalar_to_expanded_key(scalar: &[u8; 32]) -> [u8; 64] {
+    let nonce = Sha512::digest(scalar);
+    let mut expanded = [0u8; 64];
+    expanded[..32].copy_from_slice(scalar);
+    expanded[32..].copy_from_slice(&nonce[32..]);
+    expanded
+}
+
+/// 随机 ed25519 标量（已模 l 约简）
+fn random_ed25519_scalar() -> Ed25519Scalar {
+    let mut wide = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut wide);
+    Ed25519Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+/// CREATE2 合约地址：keccak256(前缀 ‖ 部署者 ‖ salt ‖ initCodeHash)[12..]
+///
+/// EVM 前缀为 0xff；TRON 的 CREATE2 使用地址版本字节 0x41 作为前缀，结果按 Base58Check 编码。
+pub fn create2_address(
+    chain: ChainType,
+    deployer: &[u8; 20],
+    salt: &[u8; 32],
+    init_code_hash: &[u8; 32],
+) -> Result<String, VanityError> {
+    let mut data = [0u8; 85];
+    data[0] = chain.chain().create2_prefix()?;
+    data[1..21].copy_from_slice(deployer);
+    data[21..53].copy_from_slice(salt);
+    data[53..].copy_from_slice(init_code_hash);
+    chain.chain().account_to_address(&keccak_account(&data))
+}
+
+/// CREATE2 盐值搜索结果（不涉及私钥）
+#[derive(Clone, Debug)]
+pub struct Create2Result {
+    pub chain: ChainType,
+    /// 合约地址
+    pub address: String,
+    /// 盐值（0x 开头的 32 字节 hex）
+    pub salt: String,
+    /// 部署者（工厂合约）地址
+    pub deployer: String,
+    /// initCode 的 keccak256（0x 开头 hex）
+    pub init_code_hash: String,
+}
+
+/// CREATE2 盐值搜索器：salt = 固定前缀 ‖ 随机填充 ‖ 8 字节递增计数器
+///
+/// 每个线程各建一个搜索器，随机填充保证各线程的搜索空间互不重叠；
+/// 固定前缀可填部署者地址等，以满足工厂合约对 salt 的约束。
+#[derive(Clone, Debug)]
+pub struct Create2Miner {
+    chain: ChainType,
+    deployer: [u8; 20],
+    init_code_hash: [u8; 32],
+    salt: [u8; 32],
+}
+
+impl Create2Miner {
+    /// 固定前缀最长 24 字节（后 8 字节留给计数器）
+    pub fn new(
+        chain: ChainType,
+        deployer: &str,
+        init_code_hash: &[u8],
+        salt_prefix: &[u8],
+    ) -> Result<Self, VanityError> {
+        chain.chain().create2_prefix()?;
+        let deployer = parse_account(chain, deployer)?;
+        let init_code_hash: [u8; 32] = init_code_hash.try_into().map_err(|_| {
+            VanityError::InvalidParameter(format!(
+                "init code hash 须为 32 字节 | init code hash must be 32 bytes: {}",
+                hex::encode(init_code_hash)
+            ))
+        })?;
+        if salt_prefix.len() > 24 {
+            return Err(VanityError::InvalidParameter(format!(
+                "salt 前缀最长 24 字节 | salt prefix longer than 24 bytes: {}",
+                salt_prefix.len()
+            )));
+        }
+
+        let mut salt = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut salt[salt_prefix.len()..]);
+        salt[..salt_prefix.len()].copy_from_slice(salt_prefix);
+        Ok(Self {
+            chain,
+            deployer,
+            init_code_hash,
+            salt,
+        })
+    }
+
+    /// 尝试下一个 salt
+    pub fn next_candidate(&mut self) -> Create2Result {
+        let counter = u64::from_be_bytes(self.salt[24..].try_into().unwrap_or_default());
+        self.salt[24..].copy_from_slice(&counter.wrapping_add(1).to_be_bytes());
+
+        let address = create2_address(self.chain, &self.deployer, &self.salt, &self.init_code_hash)
+            .unwrap_or_default();
+        Create2Result {
+            chain: self.chain,
+            address,
+            salt: format!("0x{}", hex::encode(self.salt)),
+            deployer: self
+                .chain
+                .chain()
+                .account_to_address(&self.deployer)
+                .unwrap_or_default(),
+            init_code_hash: format!("0x{}", hex::encode(self.init_code_hash)),
+        }
+    }
+}
+
+/// Keystore V3 使用的 AES-128-CTR（大端计数器）
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// Keystore V3（Web3 Secret Storage）的密钥派生函数及参数
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KeystoreKdf {
+    /// scrypt，n = 2^log_n
+    Scrypt { log_n: u8, r: u32, p: u32 },
+    /// PBKDF2-HMAC-SHA256
+    Pbkdf2 { iterations: u32 },
+}
+
+impl KeystoreKdf {
+    /// geth / MetaMask 默认强度：n=262144, r=8, p=1（约 256 MB 内存）
+    pub const SCRYPT: KeystoreKdf = KeystoreKdf::Scrypt {
+        log_n: 18,
+        r: 8,
+        p: 1,
+    };
+    /// geth --lightkdf 强度：n=4096, r=8, p=6，适合低内存机器
+    pub const SCRYPT_LIGHT: KeystoreKdf = KeystoreKdf::Scrypt {
+        log_n: 12,
+        r: 8,
+        p: 6,
+    };
+    /// PBKDF2 默认强度：262144 轮
+    pub const PBKDF2: KeystoreKdf = KeystoreKdf::Pbkdf2 {
+        iterations: 262_144,
+    };
+}
+
+impl Default for KeystoreKdf {
+    fn default() -> Self {
+        KeystoreKdf::SCRYPT
+    }
+}
+
+impl std::fmt::Display for KeystoreKdf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeystoreKdf::Scrypt { log_n, r, p } => {
+                write!(f, "scrypt (n={}, r={}, p={})", 1u64 << log_n, r, p)
+            }
+            KeystoreKdf::Pbkdf2 { iterations } => write!(f, "pbkdf2 (c={})", iterations),
+        }
+    }
+}
+
+/// Keystore V3 JSON 结构
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeystoreFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    /// 旧版 geth 写作 "Crypto"
+    #[serde(alias = "Crypto")]
+    crypto: KeystoreCrypto,
+    id: String,
+    version: u32,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    cipherparams: KeystoreCipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KeystoreKdfParams,
+    mac: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeystoreCipherParams {
+    iv: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum KeystoreKdfParams {
+    Scrypt {
+        dklen: usize,
+        n: u64,
+        r: u32,
+        p: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        c: u32,
+        dklen: usize,
+        prf: String,
+        salt: String,
+    },
+}
+
+/// 导入时接受的 KDF 参数上限：scrypt n ≤ 2^20、r ≤ 32、p ≤ 16 且内存 128·n·r 不超过 1 GiB，
+/// PBKDF2 迭代次数不超过 1000 万，防止不可信的 keystore 文件耗尽内存或 CPU
+const KEYSTORE_SCRYPT_MAX_LOG_N: u32 = 20;
+const KEYSTORE_SCRYPT_MAX_R: u32 = 32;
+const KEYSTORE_SCRYPT_MAX_P: u32 = 16;
+const KEYSTORE_SCRYPT_MAX_MEMORY: u64 = 1 << 30;
+const KEYSTORE_PBKDF2_MAX_C: u32 = 10_000_000;
+
+/// 按 kdfparams 由密码派生密钥（dklen 至少 32 字节）
+fn keystore_derive_key(password: &str, params: &KeystoreKdfParams) -> Result<Vec<u8>, VanityError> {
+    let invalid = |e: &str| VanityError::Keystore(e.to_string());
+    let (dklen, salt) = match params {
+        KeystoreKdfParams::Scrypt { dklen, salt, .. }
+        | KeystoreKdfParams::Pbkdf2 { dklen, salt, .. } => {
+            (*dklen, hex::decode(salt).map_err(|_| invalid("salt"))?)
+        }
+    };
+    if !(32..=64).contains(&dklen) {
+        return Err(invalid("dklen"));
+    }
+    let mut derived = vec![0u8; dklen];
+    match params {
+        KeystoreKdfParams::Scrypt { n, r, p, .. } => {
+            if !n.is_power_of_two() || *n < 2 || n.trailing_zeros() > KEYSTORE_SCRYPT_MAX_LOG_N {
+                return Err(invalid("scrypt n"));
+            }
+            if *r == 0
+                || *r > KEYSTORE_SCRYPT_MAX_R
+                || *p == 0
+                || *p > KEYSTORE_SCRYPT_MAX_P
+                || 128 * n * u64::from(*r) > KEYSTORE_SCRYPT_MAX_MEMORY
+            {
+                return Err(invalid("scrypt r / p"));
+            }
+            let log_n = n.trailing_zeros() as u8;
+            let scrypt_params =
+                scrypt::Params::new(log_n, *r, *p, dklen).map_err(|_| invalid("scrypt params"))?;
+            scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, &mut derived)
+                .map_err(|_| invalid("scrypt dklen"))?;
+        }
+        KeystoreKdfParams::Pbkdf2 { c, prf, .. } => {
+            if prf != "hmac-sha256" {
+                return Err(invalid("prf"));
+            }
+            if *c == 0 || *c > KEYSTORE_PBKDF2_MAX_C {
+                return Err(invalid("pbkdf2 c"));
+            }
+            pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, *c, &mut derived);
+        }
+    }
+    Ok(derived)
+}
+
+/// MAC = keccak256(派生密钥[16..32] ‖ 密文)
+fn keystore_mac(derived: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    let mut data = derived[16..32].to_vec();
+    data.extend_from_slice(ciphertext);
+    keccak256(&data)
+}
+
+/// 随机 UUID v4，作为 keystore 的 id
+fn random_uuid() -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// 把 TRON/EVM 私钥加密为 Keystore V3 JSON（AES-128-CTR + Keccak MAC）
+///
+/// address 字段由 [`Chain::keystore_address`] 给出：EVM 为不带 0x 的小写十六进制（与 geth 一致），TRON 为 Base58 地址。
+pub fn encrypt_keystore(
+    chain: ChainType,
+    private_key: &[u8; 32],
+    password: &str,
+    kdf: KeystoreKdf,
+) -> Result<String, VanityError> {
+    let addr = chain.chain().address_from_private_key(private_key, None)?;
+    let address = chain
+        .chain()
+        .keystore_address(&addr)
+        .ok_or(VanityError::UnsupportedChain(chain))?;
+
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; 32];
+    let mut iv = [0u8; 16];
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut iv);
+
+    let kdfparams = match kdf {
+        KeystoreKdf::Scrypt { log_n, r, p } => KeystoreKdfParams::Scrypt {
+            dklen: 32,
+            n: 1u64 << log_n,
+            r,
+            p,
+            salt: hex::encode(salt),
+        },
+        KeystoreKdf::Pbkdf2 { iterations } => KeystoreKdfParams::Pbkdf2 {
+            c: iterations,
+            dklen: 32,
+            prf: "hmac-sha256".to_string(),
+            salt: hex::encode(salt),
+        },
+    };
+    let mut derived = keystore_derive_key(password, &kdfparams)?;
+
+    let mut ciphertext = private_key.to_vec();
+    Aes128Ctr::new(derived[..16].into(), (&iv).into()).apply_keystream(&mut ciphertext);
+    let mac = keystore_mac(&derived, &ciphertext);
+    derived.zeroize();
+
+    let keystore = KeystoreFile {
+        address: Some(address),
+        crypto: KeystoreCrypto {
+            cipher: "aes-128-ctr".to_string(),
+            cipherparams: KeystoreCipherParams {
+                iv: hex::encode(iv),
+            },
+            ciphertext: hex::encode(ciphertext),
+            kdf: match kdf {
+                KeystoreKdf::Scrypt { .. } => "scrypt",
+                KeystoreKdf::Pbkdf2 { .. } => "pbkdf2",
+            }
+            .to_string(),
+            kdfparams,
+            mac: hex::encode(mac),
+        },
+        id: random_uuid(),
+        version: 3,
+    };
+    serde_json::to_string_pretty(&keystore).map_err(|e| VanityError::Keystore(e.to_string()))
+}
+
+/// 解析 Keystore V3 JSON 并用密码解密出私钥；MAC 不符时返回 [`VanityError::WrongPassword`]
+///
+/// scrypt 参数须满足 RFC 7914 的 n < 2^(16·r)（geth、MetaMask 使用的 r=8 均满足）。
+pub fn decrypt_keystore(json: &str, password: &str) -> Result<[u8; 32], VanityError> {
+    let keystore: KeystoreFile =
+        serde_json::from_str(json).map_err(|e| VanityError::Keystore(e.to_string()))?;
+    let invalid = |e: &str| VanityError::Keystore(e.to_string());
+    let crypto = &keystore.crypto;
+    if keystore.version != 3 {
+        return Err(invalid("version"));
+    }
+    if crypto.cipher != "aes-128-ctr" {
+        return Err(invalid("cipher"));
+    }
+    let kdf_matches = matches!(
+        (crypto.kdf.as_str(), &crypto.kdfparams),
+        ("scrypt", KeystoreKdfParams::Scrypt { .. }) | ("pbkdf2", KeystoreKdfParams::Pbkdf2 { .. })
+    );
+    if !kdf_matches {
+        return Err(invalid("kdf"));
+    }
+
+    let iv: [u8; 16] = hex::decode(&crypto.cipherparams.iv)
+        .ok()
+        .and_then(|iv| iv.try_into().ok())
+        .ok_or_else(|| invalid("iv"))?;
+    let mut ciphertext = hex::decode(&crypto.ciphertext).map_err(|_| invalid("ciphertext"))?;
+    let mac = hex::decode(&crypto.mac).map_err(|_| invalid("mac"))?;
+
+    let mut derived = keystore_derive_key(password, &crypto.kdfparams)?;
+    let expected = keystore_mac(&derived, &ciphertext);
+    if !bool::from(mac.as_slice().ct_eq(&expected)) {
+        derived.zeroize();
+        return Err(VanityError::WrongPassword);
+    }
+    Aes128Ctr::new(derived[..16].into(), (&iv).into()).apply_keystream(&mut ciphertext);
+    derived.zeroize();
+
+    let private_key: Result<[u8; 32], _> = ciphertext.as_slice().try_into();
+    ciphertext.zeroize();
+    private_key.map_err(|_| VanityError::InvalidPrivateKey)
+}
+
+/// 导入 Keystore：解密私钥并重建该链地址；文件带 address 字段时须与私钥一致
+///
+/// secp256k1 私钥在 TRON 与 EVM 间通用，因此 geth 导出的文件也可按 TRON 导入。
+pub fn import_keystore(
+    chain: ChainType,
+    json: &str,
+    password: &str,
+) -> Result<VanityAddress, VanityError> {
+    let private_key = decrypt_keystore(json, password)?;
+    let addr = chain.chain().address_from_private_key(&private_key, None)?;
+    if chain.chain().keystore_address(&addr).is_none() {
+        return Err(VanityError::UnsupportedChain(chain));
+    }
+
+    let keystore: KeystoreFile =
+        serde_json::from_str(json).map_err(|e| VanityError::Keystore(e.to_string()))?;
+    // address 字段可能是 geth 的 40 位十六进制账户，也可能是该链自己的地址格式
+    if let Some(address) = keystore.address.as_deref() {
+        let recorded = match hex::decode(address.trim_start_matches("0x")) {
+            Ok(account) if account.len() == 20 => account,
+            _ => parse_account(chain, address)?.to_vec(),
+        };
+        if recorded != parse_account(chain, &addr.address)? {
+            return Err(VanityError::Keystore(format!(
+                "address 字段与私钥不符 | address does not match key: {}",
+                address
+            )));
+        }
+    }
+    Ok(addr)
+}
+
+/// Solana 64 字节密钥对：ed25519 种子 ‖ 公钥，即 solana-keygen 与 Phantom 使用的格式
+///
+/// 分离密钥合成的 SOL 结果只有标量而没有种子，无法导出为密钥对。
+pub fn sol_keypair_bytes(addr: &VanityAddress) -> Result<[u8; 64], VanityError> {
+    if addr.chain != ChainType::Sol {
+        return Err(VanityError::UnsupportedChain(addr.chain));
+    }
+    let mut seed: [u8; 32] = hex::decode(addr.private_key.expose_secret())
+        .ok()
+        .and_then(|seed| seed.try_into().ok())
+        .ok_or(VanityError::InvalidPrivateKey)?;
+    let secret = SecretKey::from_bytes(&seed).map_err(|_| VanityError::InvalidPrivateKey)?;
+    let public: PublicKey = (&secret).into();
+    if !addr
+        .public_key
+        .eq_ignore_ascii_case(&hex::encode(public.as_bytes()))
+    {
+        seed.zeroize();
+        return Err(VanityError::InvalidPrivateKey);
+    }
+
+    let mut keypair = [0u8; 64];
+    keypair[..32].copy_from_slice(&seed);
+    keypair[32..].copy_from_slice(public.as_bytes());
+    seed.zeroize();
+    Ok(keypair)
+}
+
+/// solana-keygen 密钥对文件内容：64 字节的 JSON 数组
+pub fn sol_keypair_json(addr: &VanityAddress) -> Result<SecretString, VanityError> {
+    let mut keypair = sol_keypair_bytes(addr)?;
+    let json = format!(
+        "[{}]",
+        keypair
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    keypair.zeroize();
+    Ok(json.into())
+}
+
+/// Phantom 可导入的私钥：64 字节密钥对的 Base58 编码
+pub fn sol_phantom_secret(addr: &VanityAddress) -> Result<SecretString, VanityError> {
+    let mut keypair = sol_keypair_bytes(addr)?;
+    let secret = bs58::encode(keypair).into_string();
+    keypair.zeroize();
+    Ok(secret.into())
+}
+
+/// 导入 solana-keygen JSON 数组或 Phantom Base58 私钥，由种子重建地址并校验后 32 字节公钥
+pub fn import_sol_keypair(input: &str) -> Result<VanityAddress, VanityError> {
+    let input = input.trim();
+    let mut bytes = if input.starts_with('[') {
+        serde_json::from_str::<Vec<u8>>(input).map_err(|_| VanityError::InvalidPrivateKey)?
+    } else {
+        bs58::decode(input)
+            .into_vec()
+            .map_err(|_| VanityError::InvalidPrivateKey)?
+    };
+    if bytes.len() != 64 {
+        bytes.zeroize();
+        return Err(VanityError::InvalidPrivateKey);
+    }
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&bytes[..32]);
+    let addr = sol_address_from_seed(&seed, None);
+    seed.zeroize();
+    let public_key = hex::encode(&bytes[32..]);
+    bytes.zeroize();
+
+    let addr = addr?;
+    if addr.public_key != public_key {
+        return Err(VanityError::InvalidPublicKey);
+    }
+    Ok(addr)
+}
+
+/// 裸私钥模式命中时，在助记词位置展示的提示
+pub const NO_MNEMONIC_NOTICE: &str =
+    "无助记词备份（裸私钥模式，请务必妥善保存私钥）| NONE - raw key, no mnemonic backup";
+
+/// 使用了 BIP39 密码时，在结果中展示的提示（密码本身从不输出或写入文件）
+pub const PASSPHRASE_NOTICE: &str =
+    "已使用（未保存，恢复时需同时提供）| used (not saved, required for recovery)";
+
+/// 靓号匹配方式
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MatchKind {
+    /// 紧跟链固定开头（TRON 的 T、EVM 的 0x）之后
+    Prefix,
+    /// 地址末尾
+    Suffix,
+    /// 地址任意位置
+    Contains,
+    /// 从固定开头之后第 N 个字符（0 起）开始
+    Position(usize),
+}
+
+/// 靓号匹配模式
+///
+/// 语法在 CLI 输入与 GUI 模式框中通用，多个模式用逗号分隔：
+/// - `8888`：后缀
+/// - `^8888`：前缀，即 TRON 的 `T8888…`、EVM 的 `0x8888…`
+/// - `*8888*`：包含
+/// - `@3:8888`：固定开头之后第 3 个字符起为 `8888`
+/// - 任一模式前加 `!` 表示区分大小写，如 `!^AAAA`；EVM 按 EIP-55 校验和大小写匹配
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Pattern {
+    pub kind: MatchKind,
+    pub text: String,
+    pub case_sensitive: bool,
+}
+
+impl Pattern {
+    /// 创建不区分大小写的模式
+    pub fn new(kind: MatchKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            case_sensitive: false,
+        }
+    }
+
+    /// 创建区分大小写的模式
+    pub fn case_sensitive(kind: MatchKind, text: &str) -> Self {
+        Self {
+            case_sensitive: true,
+            ..Self::new(kind, text)
+        }
+    }
+
+    /// 解析单个模式
+    pub fn parse(input: &str) -> Result<Self, VanityError> {
+        let input = input.trim();
+        let (case_sensitive, rest) = match input.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        let (kind, text) = if let Some(rest) = rest.strip_prefix('@') {
+            let (position, text) = rest.split_once(':').ok_or_else(|| {
+                VanityError::InvalidPattern(format!("{} (位置模式格式为 @N:text)", input))
+            })?;
+            let position = position.trim().parse().map_err(|_| {
+                VanityError::InvalidPattern(format!("{} (位置必须是非负整数)", input))
+            })?;
+            (MatchKind::Position(position), text)
+        } else if let Some(text) = rest.strip_prefix('^') {
+            (MatchKind::Prefix, text)
+        } else if let Some(text) = rest
+            .strip_prefix('*')
+            .and_then(|rest| rest.strip_suffix('*'))
+        {
+            (MatchKind::Contains, text)
+        } else {
+            (MatchKind::Suffix, rest)
+        };
+
+        if text.is_empty() {
+            return Err(VanityError::InvalidPattern(format!(
+                "{} (模式内容为空)",
+                input
+            )));
+        }
+        Ok(Self {
+            case_sensitive,
+            ..Self::new(kind, text)
+        })
+    }
+
+    /// 检查地址是否满足该模式（EVM 地址为 EIP-55 校验和格式，区分大小写时只有校验和恰好给出该大小写才算命中）
+    pub fn matches(&self, chain: ChainType, address: &str) -> bool {
+        let body = address
+            .strip_prefix(chain.address_prefix())
+            .unwrap_or(address);
+        let (body, text) = if self.case_sensitive {
+            (body.to_string(), self.text.clone())
+        } else {
+            (body.to_ascii_lowercase(), self.text.to_ascii_lowercase())
+        };
+
+        match self.kind {
+            MatchKind::Prefix => body.starts_with(&text),
+            MatchKind::Suffix => body.ends_with(&text),
+            MatchKind::Contains => body.contains(&text),
+            MatchKind::Position(position) => body
+                .get(position..)
+                .is_some_and(|rest| rest.starts_with(&text)),
+        }
+    }
+
+    pub fn case_label(&self) -> &'static str {
+        if self.case_sensitive {
+            "区分大小写 | case-sensitive"
+        } else {
+            "不区分大小写 | case-insensitive"
+        }
+    }
+
+    /// 期望尝试次数：平均生成多少个地址才命中一次，不可能命中时为无穷大
+    pub fn difficulty(&self, chain: ChainType) -> f64 {
+        estimate_difficulty(chain, self).expected_attempts()
+    }
+}
+
+/// Base58（Bitcoin 字母表），TRON 与 Solana 地址使用
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// TRON 地址 T 之后第一位可能出现的字符
+///
+/// 载荷固定为 0x41 + 20 字节哈希 + 4 字节校验和，对最小 / 最大载荷做 Base58 编码，
+/// 第二位字符的取值就是两者之间的连续区间。
+fn tron_leading_chars() -> &'static str {
+    static CHARS: OnceLock<String> = OnceLock::new();
+    CHARS.get_or_init(|| {
+        let mut low = [0x00u8; 25];
+        let mut high = [0xffu8; 25];
+        low[0] = 0x41;
+        high[0] = 0x41;
+
+        let second = |payload: [u8; 25]| {
+            let encoded = bs58::encode(payload).into_string();
+            encoded.chars().nth(1).and_then(|c| BASE58_ALPHABET.find(c))
+        };
+        match (second(low), second(high)) {
+            (Some(first), Some(last)) => BASE58_ALPHABET[first..=last].to_string(),
+            _ => BASE58_ALPHABET.to_string(),
+        }
+    })
+}
+
+/// 地址某一位恰好匹配字符 c 的概率
+fn char_match_probability(chain: ChainType, c: char, case_sensitive: bool) -> f64 {
+    if chain.chain().checksum_case() {
+        let size = address_symbols(chain).count() as f64;
+        if !address_symbols(chain).any(|a| a.eq_ignore_ascii_case(&c)) {
+            0.0
+        } else if case_sensitive && c.is_ascii_alphabetic() {
+            // 校验和恰好给出该大小写
+            0.5 / size
+        } else {
+            1.0 / size
+        }
+    } else {
+        let hits = chain
+            .alphabet()
+            .chars()
+            .filter(|a| {
+                if case_sensitive {
+                    *a == c
+                } else {
+                    a.eq_ignore_ascii_case(&c)
+                }
+            })
+            .count();
+        hits as f64 / chain.alphabet().chars().count() as f64
+    }
+}
+
+/// 地址每一位的符号集合，大小写由校验和决定的链只保留小写形态
+fn address_symbols(chain: ChainType) -> impl Iterator<Item = char> {
+    let checksum_case = chain.chain().checksum_case();
+    chain
+        .alphabet()
+        .chars()
+        .filter(move |a| !(checksum_case && a.is_ascii_uppercase()))
+}
+
+/// 格式化期望尝试次数
+pub fn format_attempts(attempts: f64) -> String {
+    if !attempts.is_finite() {
+        "∞ (不可能命中 | impossible)".to_string()
+    } else if attempts < 1e6 {
+        format!("{:.0}", attempts)
+    } else {
+        format!("{:.2e}", attempts)
+    }
+}
+
+/// 命中难度：单次尝试（生成一个候选）命中的概率
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Difficulty {
+    pub probability: f64,
+}
+
+impl Difficulty {
+    /// 平均需要的尝试次数
+    pub fn expected_attempts(&self) -> f64 {
+        1.0 / self.probability
+    }
+
+    /// 至少命中一次的概率达到 confidence（如 0.5、0.9）所需的尝试次数
+    pub fn attempts_for(&self, confidence: f64) -> f64 {
+        if self.probability <= 0.0 {
+            f64::INFINITY
+        } else if self.probability >= 1.0 {
+            1.0
+        } else {
+            (-confidence).ln_1p() / (-self.probability).ln_1p()
+        }
+    }
+
+    /// 已尝试 attempts 次后至少命中一次的概率
+    pub fn probability_after(&self, attempts: u64) -> f64 {
+        if self.probability >= 1.0 {
+            return if attempts > 0 { 1.0 } else { 0.0 };
+        }
+        -(attempts as f64 * (-self.probability).ln_1p()).exp_m1()
+    }
+
+    /// 以 rate（次/秒）继续搜索，从已尝试 attempts 次到命中概率达到 confidence 还需的秒数
+    pub fn eta_seconds(&self, confidence: f64, attempts: u64, rate: f64) -> f64 {
+        let remaining = (self.attempts_for(confidence) - attempts as f64).max(0.0);
+        if remaining == 0.0 {
+            0.0
+        } else if rate > 0.0 {
+            remaining / rate
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+/// 估算模式在该链上的命中难度
+///
+/// 每一位按链的字母表计算：Base58 为 1/58，十六进制为 1/16；不区分大小写时字母的大小写形态都算命中，
+/// 区分大小写时只有一种形态，EVM 还要求 EIP-55 校验和恰好给出该大小写，概率再减半。
+/// TRON 的 T 之后第一位只有约 23 种取值，前缀 / 第 0 位按实际区间计算。
+pub fn estimate_difficulty(chain: ChainType, pattern: &Pattern) -> Difficulty {
+    if validate_pattern(chain, pattern).is_err() {
+        return Difficulty { probability: 0.0 };
+    }
+
+    let body_len = chain.chain().address_body_len();
+    let len = pattern.text.chars().count();
+    let leading_anchored = chain.chain().leading_chars().is_some()
+        && matches!(pattern.kind, MatchKind::Prefix | MatchKind::Position(0));
+
+    let p: f64 = pattern
+        .text
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if leading_anchored && i == 0 {
+                chain
+                    .chain()
+                    .leading_char_probability(c, pattern.case_sensitive)
+            } else {
+                char_match_probability(chain, c, pattern.case_sensitive)
+            }
+        })
+        .product();
+    let probability = match pattern.kind {
+        // 近似：各起始位置相互独立
+        MatchKind::Contains => -((body_len - len + 1) as f64 * (-p).ln_1p()).exp_m1(),
+        _ => p,
+    };
+    Difficulty { probability }
+}
+
+/// 估算一组模式在多条链上同时搜索时的难度（每个候选包含每条链各一个地址，任一命中即算命中）；
+/// 未设置模式时按末尾连续 3 个相同字符的默认规则估算
+pub fn estimate_combined_difficulty(chains: &[ChainType], patterns: &[Pattern]) -> Difficulty {
+    let miss: f64 = chains
+        .iter()
+        .map(|&chain| {
+            if patterns.is_empty() {
+                1.0 - repeated_tail_probability(chain)
+            } else {
+                patterns
+                    .iter()
+                    .map(|pattern| 1.0 - estimate_difficulty(chain, pattern).probability)
+                    .product()
+            }
+        })
+        .product();
+    Difficulty {
+        probability: 1.0 - miss,
+    }
+}
+
+/// TRON 地址 T 之后第一位为 c 的概率：每个取值覆盖 58^32 个载荷值，载荷区间共 256^24 个值
+fn tron_leading_char_probability(c: char, case_sensitive: bool) -> f64 {
+    let hits = tron_leading_chars()
+        .chars()
+        .filter(|a| {
+            if case_sensitive {
+                *a == c
+            } else {
+                a.eq_ignore_ascii_case(&c)
+            }
+        })
+        .count();
+    (hits as f64 * 58f64.powi(32) / 256f64.powi(24)).min(1.0)
+}
+
+/// 默认规则（末尾连续 3 个字符不区分大小写相同）的命中概率
+fn repeated_tail_probability(chain: ChainType) -> f64 {
+    let size = address_symbols(chain).count() as f64;
+    // 相邻两位相同的概率
+    let same: f64 = address_symbols(chain)
+        .map(|a| {
+            address_symbols(chain)
+                .filter(|b| a.eq_ignore_ascii_case(b))
+                .count() as f64
+        })
+        .sum::<f64>()
+        / (size * size);
+    same * same
+}
+
+/// 格式化预计耗时
+pub fn format_eta(seconds: f64) -> String {
+    if !seconds.is_finite() {
+        return "∞".to_string();
+    }
+    let seconds = seconds.round() as u64;
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        3600..=86_399 => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+        86_400..=31_535_999 => format!("{}d {}h", seconds / 86_400, seconds % 86_400 / 3600),
+        _ => format!("{:.1e} 年 | years", seconds as f64 / 31_536_000.0),
+    }
+}
+
+impl std::str::FromStr for Pattern {
+    type Err = VanityError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl std::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.case_sensitive {
+            write!(f, "!")?;
+        }
+        match self.kind {
+            MatchKind::Prefix => write!(f, "^{}", self.text),
+            MatchKind::Suffix => write!(f, "{}", self.text),
+            MatchKind::Contains => write!(f, "*{}*", self.text),
+            MatchKind::Position(position) => write!(f, "@{}:{}", position, self.text),
+        }
+    }
+}
+
+/// 检查模式在该链上能否命中：字符必须在链的字母表内（按大小写模式），长度不能超出地址，
+/// TRON 的前缀 / 第 0 位还必须落在 T 之后可能出现的字符区间
+pub fn validate_pattern(chain: ChainType, pattern: &Pattern) -> Result<(), VanityError> {
+    let impossible = |character, alphabet| {
+        Err(VanityError::ImpossiblePattern {
+            chain,
+            pattern: pattern.to_string(),
+            character,
+            alphabet,
+        })
+    };
+
+    if let Some(c) = pattern
+        .text
+        .chars()
+        .find(|&c| char_match_probability(chain, c, pattern.case_sensitive) == 0.0)
+    {
+        return impossible(Some(c), chain.alphabet());
+    }
+
+    let len = pattern.text.chars().count();
+    let end = match pattern.kind {
+        MatchKind::Position(position) => position + len,
+        _ => len,
+    };
+    if end > chain.chain().address_body_len() {
+        return impossible(None, chain.alphabet());
+    }
+
+    if let Some(leading) = chain
+        .chain()
+        .leading_chars()
+        .filter(|_| matches!(pattern.kind, MatchKind::Prefix | MatchKind::Position(0)))
+    {
+        if let Some(c) = pattern.text.chars().next().filter(|&c| {
+            !leading.chars().any(|a| {
+                if pattern.case_sensitive {
+                    a == c
+                } else {
+                    a.eq_ignore_ascii_case(&c)
+                }
+            })
+        }) {
+            return impossible(Some(c), leading);
+        }
+    }
+
+    Ok(())
+}
+
+/// 检查要搜索的所有链：某个模式在每条链上都不可能命中时返回错误；
+/// 只在部分链上不可能时照常搜索，返回这些链的警告
+pub fn check_patterns(
+    chains: &[ChainType],
+    patterns: &[Pattern],
+) -> Result<Vec<VanityError>, VanityError> {
+    let mut warnings = Vec::new();
+    for pattern in patterns {
+        let mut errors: Vec<VanityError> = chains
+            .iter()
+            .filter_map(|&chain| validate_pattern(chain, pattern).err())
+            .collect();
+        if !chains.is_empty() && errors.len() == chains.len() {
+            return Err(errors.remove(0));
+        }
+        warnings.extend(errors);
+    }
+    Ok(warnings)
+}
+
+/// 解析逗号分隔的模式列表（空项忽略）
+pub fn parse_patterns(input: &str) -> Result<Vec<Pattern>, VanityError> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(Pattern::parse)
+        .collect()
+}
+
+/// 检查是否为靓号：满足任一模式；未设置模式时使用末尾连续 >=3 相同字符的默认规则
+pub fn matches_patterns(chain: ChainType, address: &str, patterns: &[Pattern]) -> bool {
+    if patterns.is_empty() {
+        return has_repeated_tail(address);
+    }
+    patterns
+        .iter()
+        .any(|pattern| pattern.matches(chain, address))
+}
+
+/// 预编译的多模式匹配器：每次搜索构建一次，在工作线程间只读共享
+///
+/// 前缀和后缀模式分别放进正向 / 反向字典树，每个地址只需沿树走一遍，与模式数量无关；
+/// 不区分大小写的模式预先转小写，匹配时逐字节比较，不再为每个地址分配字符串。
+/// 包含与指定位置模式数量通常很少，逐个检查。
+/// 未设置模式时使用默认规则（末尾连续 3 个相同字符），展开为等价的后缀模式。
+#[derive(Debug, Clone)]
+pub struct PatternMatcher {
+    patterns: Vec<Pattern>,
+    /// [不区分大小写, 区分大小写]
+    prefixes: [ByteTrie; 2],
+    suffixes: [ByteTrie; 2],
+    /// 包含 / 指定位置模式在 patterns 中的下标
+    scanned: Vec<usize>,
+}
+
+impl PatternMatcher {
+    pub fn new(patterns: &[Pattern]) -> Self {
+        let patterns = if patterns.is_empty() {
+            // 与 has_repeated_tail 等价：末尾 3 个相同的字母或数字，不区分大小写
+            ('0'..='9')
+                .chain('a'..='z')
+                .map(|c| Pattern::new(MatchKind::Suffix, &c.to_string().repeat(3)))
+                .collect()
+        } else {
+            patterns.to_vec()
+        };
+
+        let mut prefixes = [ByteTrie::default(), ByteTrie::default()];
+        let mut suffixes = [ByteTrie::default(), ByteTrie::default()];
+        let mut scanned = Vec::new();
+        for (id, pattern) in patterns.iter().enumerate() {
+            let case = usize::from(pattern.case_sensitive);
+            let text = if pattern.case_sensitive {
+                pattern.text.clone().into_bytes()
+            } else {
+                pattern.text.to_ascii_lowercase().into_bytes()
+            };
+            match pattern.kind {
+                MatchKind::Prefix => prefixes[case].insert(text.iter().copied(), id),
+                MatchKind::Suffix => suffixes[case].insert(text.iter().rev().copied(), id),
+                _ => scanned.push(id),
+            }
+        }
+
+        Self {
+            patterns,
+            prefixes,
+            suffixes,
+            scanned,
+        }
+    }
+
+    /// 实际参与匹配的模式（未设置模式时为默认规则展开的后缀）
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    /// 返回地址命中的模式（多个模式同时命中时返回其中之一）
+    pub fn find(&self, chain: ChainType, address: &str) -> Option<&Pattern> {
+        let body = address
+            .strip_prefix(chain.address_prefix())
+            .unwrap_or(address)
+            .as_bytes();
+
+        let id = self
+            .prefixes
+            .iter()
+            .zip([false, true])
+            .find_map(|(trie, case_sensitive)| trie.find(body.iter().copied(), case_sensitive))
+            .or_else(|| {
+                self.suffixes
+                    .iter()
+                    .zip([false, true])
+                    .find_map(|(trie, case_sensitive)| {
+                        trie.find(body.iter().rev().copied(), case_sensitive)
+                    })
+            })
+            .or_else(|| {
+                self.scanned
+                    .iter()
+                    .copied()
+                    .find(|&id| self.patterns[id].matches(chain, address))
+            })?;
+        Some(&self.patterns[id])
+    }
+
+    pub fn is_match(&self, chain: ChainType, address: &str) -> bool {
+        self.find(chain, address).is_some()
+    }
+}
+
+/// 按字节的字典树，节点存放在 Vec 中，子节点为 (字节, 下标) 列表
+#[derive(Debug, Clone, Default)]
+struct ByteTrie {
+    nodes: Vec<TrieNode>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: Vec<(u8, usize)>,
+    /// 在此结束的模式下标
+    pattern: Option<usize>,
+}
+
+impl ByteTrie {
+    fn insert(&mut self, bytes: impl Iterator<Item = u8>, id: usize) {
+        if self.nodes.is_empty() {
+            self.nodes.push(TrieNode::default());
+        }
+        let mut node = 0;
+        for byte in bytes {
+            node = match self.nodes[node].children.iter().find(|(b, _)| *b == byte) {
+                Some(&(_, child)) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(TrieNode::default());
+                    self.nodes[node].children.push((byte, child));
+                    child
+                }
+            };
+        }
+        self.nodes[node].pattern.get_or_insert(id);
+    }
+
+    /// 沿输入字节下行，返回遇到的第一个（最短的）完整模式
+    fn find(&self, bytes: impl Iterator<Item = u8>, case_sensitive: bool) -> Option<usize> {
+        let mut node = self.nodes.first()?;
+        for byte in bytes {
+            let byte = if case_sensitive {
+                byte
+            } else {
+                byte.to_ascii_lowercase()
+            };
+            let &(_, child) = node.children.iter().find(|(b, _)| *b == byte)?;
+            node = &self.nodes[child];
+            if let Some(id) = node.pattern {
+                return Some(id);
+            }
+        }
+        None
+    }
+}
+
+/// 检查是否为靓号：末尾匹配模式或末尾连续 >=3 相同字符
+pub fn is_vanity_address(address: &str, patterns: &[&str]) -> bool {
+    if patterns.is_empty() {
+        return has_repeated_tail(address);
+    }
+
+    let address_lower = address.to_lowercase();
+    patterns
+        .iter()
+        .any(|pattern| address_lower.ends_with(&pattern.to_lowercase()))
+}
+
+/// 默认规则：末尾连续 >=3 个相同字符
+fn has_repeated_tail(address: &str) -> bool {
+    let chars: Vec<char> = address.to_lowercase().chars().collect();
+    if chars.len() < 3 {
+        return false;
+    }
+
+    let mut consecutive = 1;
+    for i in (1..chars.len()).rev() {
+        if chars[i] == chars[i - 1] && chars[i].is_ascii_alphanumeric() {
+            consecutive += 1;
+            if consecutive >= 3 {
+                return true;
+            }
+        } else {
+            break;
+        }
+    }
+
+    false
+}
+
+/// 靓号评分的单项特征，附带命中的字符片段
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BeautyFeature {
+    /// 末尾连续相同字符，如 …8888
+    TrailingRun(String),
+    /// 开头（T / 0x 之后）连续相同字符
+    LeadingRun(String),
+    /// 重复的数字块，如 1212、520520
+    RepeatedBlock(String),
+    /// 末尾回文，如 …12321
+    Palindrome(String),
+    /// 递增或递减序列，如 12345、9876、abcd
+    Sequence(String),
+    /// 吉利数字，如 8888、6666、520、1314
+    LuckyNumber(String),
+}
+
+impl std::fmt::Display for BeautyFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BeautyFeature::TrailingRun(s) => write!(f, "末尾连号 {}", s),
+            BeautyFeature::LeadingRun(s) => write!(f, "开头连号 {}", s),
+            BeautyFeature::RepeatedBlock(s) => write!(f, "重复块 {}", s),
+            BeautyFeature::Palindrome(s) => write!(f, "回文 {}", s),
+            BeautyFeature::Sequence(s) => write!(f, "顺子 {}", s),
+            BeautyFeature::LuckyNumber(s) => write!(f, "吉利数 {}", s),
+        }
+    }
+}
+
+/// 靓号评分：总分与各项特征的得分
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BeautyScore {
+    pub score: u32,
+    pub features: Vec<(BeautyFeature, u32)>,
+}
+
+impl BeautyScore {
+    fn add(&mut self, feature: BeautyFeature, points: u32) {
+        self.score += points;
+        self.features.push((feature, points));
+    }
+
+    /// 特征列表，如 "末尾连号 8888 (+40), 吉利数 8888 (+30)"
+    pub fn describe(&self) -> String {
+        self.features
+            .iter()
+            .map(|(feature, points)| format!("{} (+{})", feature, points))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// 吉利数字及其在末尾出现时的分值（出现在中间减半）
+const LUCKY_NUMBERS: &[(&str, u32)] = &[
+    ("1314", 30),
+    ("8888", 30),
+    ("6666", 30),
+    ("9999", 20),
+    ("520", 20),
+    ("168", 15),
+    ("888", 10),
+    ("666", 10),
+];
+
+/// 按多项特征给地址打分（不区分大小写，T / 0x 开头不计入）
+///
+/// - 末尾 / 开头连续 n(>=3) 个相同字符：10·(n-2)²
+/// - 重复数字块（块长 2~3，至少重复 2 次，取最长）：5·总长度
+/// - 末尾回文（长度 >=5，且不是同一字符）：10·(长度-4)
+/// - 递增 / 递减序列（长度 >=4，取最长）：10·(长度-3)²
+/// - 吉利数字（取分值最高的一个）：在末尾得全分，出现在中间得一半
+pub fn beauty_score(chain: ChainType, address: &str) -> BeautyScore {
+    let body = address
+        .strip_prefix(chain.address_prefix())
+        .unwrap_or(address)
+        .to_ascii_lowercase();
+    let chars: Vec<char> = body.chars().collect();
+    let n = chars.len();
+    let mut score = BeautyScore::default();
+    if n < 3 {
+        return score;
+    }
+    let text = |range: std::ops::Range<usize>| chars[range].iter().collect::<String>();
+
+    let trailing = chars
+        .iter()
+        .rev()
+        .take_while(|&&c| c == chars[n - 1])
+        .count();
+    if trailing >= 3 {
+        let points = 10 * (trailing as u32 - 2).pow(2);
+        score.add(BeautyFeature::TrailingRun(text(n - trailing..n)), points);
+    }
+
+    let leading = chars.iter().take_while(|&&c| c == chars[0]).count();
+    if leading >= 3 && leading < n {
+        let points = 10 * (leading as u32 - 2).pow(2);
+        score.add(BeautyFeature::LeadingRun(text(0..leading)), points);
+    }
+
+    let mut best_block: Option<(usize, usize)> = None;
+    for block_len in 2..=3 {
+        for start in 0..n.saturating_sub(2 * block_len - 1) {
+            let block = &chars[start..start + block_len];
+            if !block.iter().all(char::is_ascii_digit) || block.iter().all(|&c| c == block[0]) {
+                continue;
+            }
+            let mut end = start + block_len;
+            while end + block_len <= n && chars[end..end + block_len] == *block {
+                end += block_len;
+            }
+            let span = end - start;
+            if span >= 2 * block_len && best_block.is_none_or(|(s, e)| span > e - s) {
+                best_block = Some((start, end));
+            }
+        }
+    }
+    if let Some((start, end)) = best_block {
+        score.add(
+            BeautyFeature::RepeatedBlock(text(start..end)),
+            5 * (end - start) as u32,
+        );
+    }
+
+    let palindrome = (5..=n).rev().find(|&len| {
+        let tail = &chars[n - len..];
+        tail.iter().eq(tail.iter().rev()) && tail.iter().any(|&c| c != tail[0])
+    });
+    if let Some(len) = palindrome {
+        score.add(
+            BeautyFeature::Palindrome(text(n - len..n)),
+            10 * (len as u32 - 4),
+        );
+    }
+
+    let mut best_sequence: Option<(usize, usize)> = None;
+    let mut start = 0;
+    while start + 1 < n {
+        let step = chars[start + 1] as i32 - chars[start] as i32;
+        let same_class = |a: char, b: char| {
+            (a.is_ascii_digit() && b.is_ascii_digit())
+                || (a.is_ascii_alphabetic() && b.is_ascii_alphabetic())
+        };
+        let mut end = start + 1;
+        if step.abs() == 1 {
+            while end < n
+                && chars[end] as i32 - chars[end - 1] as i32 == step
+                && same_class(chars[end - 1], chars[end])
+            {
+                end += 1;
+            }
+        }
+        if end - start >= 4 && best_sequence.is_none_or(|(s, e)| end - start > e - s) {
+            best_sequence = Some((start, end));
+        }
+        start = if end - start > 1 { end - 1 } else { start + 1 };
+    }
+    if let Some((start, end)) = best_sequence {
+        let points = 10 * ((end - start) as u32 - 3).pow(2);
+        score.add(BeautyFeature::Sequence(text(start..end)), points);
+    }
+
+    let lucky = LUCKY_NUMBERS
+        .iter()
+        .filter_map(|&(number, points)| {
+            if body.ends_with(number) {
+                Some((number, points))
+            } else if body.contains(number) {
+                Some((number, points / 2))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|&(_, points)| points);
+    if let Some((number, points)) = lucky {
+        score.add(BeautyFeature::LuckyNumber(number.to_string()), points);
+    }
+
+    score
+}
+
+/// 带评分的命中结果
+#[derive(Debug, Clone)]
+pub struct ScoredAddress {
+    pub address: VanityAddress,
+    pub score: BeautyScore,
+}
+
+/// 本次会话得分最高的前 N 个结果，按分数从高到低排列
+#[derive(Debug, Clone)]
+pub struct BeautyRanking {
+    limit: usize,
+    entries: Vec<ScoredAddress>,
+}
+
+impl BeautyRanking {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            entries: Vec::new(),
+        }
+    }
+
+    /// 加入排行，返回是否进入前 N
+    pub fn push(&mut self, entry: ScoredAddress) -> bool {
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.score.score < entry.score.score)
+            .unwrap_or(self.entries.len());
+        if position >= self.limit {
+            return false;
+        }
+        self.entries.insert(position, entry);
+        self.entries.truncate(self.limit);
+        true
+    }
+
+    pub fn entries(&self) -> &[ScoredAddress] {
+        &self.entries
+    }
+}
+
+/// 打印到控制台
+pub fn print_address(addr: &VanityAddress, is_vanity: bool) {
+    if is_vanity {
+        print_address_box(addr, "║ 发现靓号! | Found Vanity Address! |");
+    } else {
+        use colored::*;
+        println!("{} {}", "地址 | Address:".bright_green(), addr.address);
+    }
+}
+
+/// 打印从已有助记词派生的地址（格式与命中结果相同）
+pub fn print_derived_address(addr: &VanityAddress) {
+    print_address_box(addr, "║ 派生地址 | Derived Address |");
+}
+
+/// 带标题框打印地址的全部字段
+fn print_address_box(addr: &VanityAddress, title: &str) {
+    use colored::*;
+
+    println!(
+        "{}",
+        "╔════════════════════════════════════════════════════════════╗".bright_yellow()
+    );
+    println!("{}", title.bright_yellow());
+    println!(
+        "{}",
+        "╠════════════════════════════════════════════════════════════╣".bright_yellow()
+    );
+    println!("{} {}", "链 | Chain:".bright_green(), addr.chain.label());
+    println!(
+        "{} {}",
+        "地址 | Address:".bright_green(),
+        addr.address.bright_cyan()
+    );
+    println!(
+        "{} {}",
+        "私钥 | Private Key:".bright_red(),
+        addr.private_key.expose_secret().bright_white()
+    );
+    println!("{} {}", "公钥 | Public Key:".bright_blue(), addr.public_key);
+    if let Some(path) = &addr.derivation_path {
+        println!("{} {}", "派生路径 | Path:".bright_blue(), path);
+    }
+    if addr.passphrase_used {
+        println!(
+            "{} {}",
+            "BIP39 密码 | Passphrase:".bright_magenta(),
+            PASSPHRASE_NOTICE.bright_red()
+        );
+    }
+    match &addr.mnemonic {
+        Some(mnemonic) => {
+            println!(
+                "{} {}",
+                "助记词 | Mnemonic:".bright_magenta(),
+                mnemonic.expose_secret()
+            );
+            println!(
+                "{} {}",
+                "助记词语言 | Language:".bright_magenta(),
+                addr.mnemonic_language.label()
+            );
+        }
+        None => println!(
+            "{} {}",
+            "助记词 | Mnemonic:".bright_magenta(),
+            NO_MNEMONIC_NOTICE.bright_red()
+        ),
+    }
+    println!(
+        "{}",
+        "╚════════════════════════════════════════════════════════════╝".bright_yellow()
+    );
+}
+
+/// 打印多链地址到控制台（命中任意链时，列出注册表中所有链）
+pub fn print_multi_address(multi: &MultiChainAddress, hit_chain: ChainType) {
+    use colored::*;
+
+    println!(
+        "{}",
+        "╔════════════════════════════════════════════════════════════╗".bright_yellow()
+    );
+    println!(
+        "{} {}",
+        "║ 发现靓号! | Found Vanity Address! |".bright_yellow(),
+        "".bright_yellow()
+    );
+    println!(
+        "{}",
+        "╠════════════════════════════════════════════════════════════╣".bright_yellow()
+    );
+    println!(
+        "{} {}",
+        "命中链 | Hit Chain:".bright_green(),
+        hit_chain.label()
+    );
+
+    for (chain, addr) in &multi.addresses {
+        println!(
+            "{} {}",
+            format!("{} 地址:", chain.label()).bright_green(),
+            addr.address.bright_cyan()
+        );
+    }
+
+    for (chain, addr) in &multi.addresses {
+        println!(
+            "{} {}",
+            format!("{} 私钥:", chain.label()).bright_red(),
+            addr.private_key.expose_secret().bright_red()
+        );
+    }
+
+    for (chain, addr) in &multi.addresses {
+        if let Some(path) = &addr.derivation_path {
+            println!(
+                "{} {}",
+                format!("{} 路径:", chain.label()).bright_blue(),
+                path
+            );
+        }
+    }
+
+    println!(
+        "{} {}",
+        "助记词 | Mnemonic:".bright_magenta(),
+        multi.mnemonic.expose_secret().bright_white()
+    );
+    println!(
+        "{} {}",
+        "助记词语言 | Language:".bright_magenta(),
+        multi.language.label()
+    );
+    if multi.passphrase_used {
+        println!(
+            "{} {}",
+            "BIP39 密码 | Passphrase:".bright_magenta(),
+            PASSPHRASE_NOTICE.bright_red()
+        );
+    }
+    println!(
+        "{}",
+        "╚════════════════════════════════════════════════════════════╝".bright_yellow()
+    );
+}
+
+/// 写入文件
+pub fn save_address_to_file(
+    filename: &str,
+    addr: &VanityAddress,
+    is_vanity: bool,
+) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    let vanity_mark = if is_vanity { "[VANITY]" } else { "[NORMAL]" };
+
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(
+        file,
+        "{} {} | Chain: {}",
+        vanity_mark,
+        timestamp,
+        addr.chain.label()
+    )?;
+    writeln!(file, "Address: {}", addr.address)?;
+    writeln!(file, "Private Key: {}", addr.private_key.expose_secret())?;
+    writeln!(file, "Public Key: {}", addr.public_key)?;
+    if let Some(path) = &addr.derivation_path {
+        writeln!(file, "Derivation Path: {}", path)?;
+    }
+    if addr.passphrase_used {
+        writeln!(file, "Passphrase: {}", PASSPHRASE_NOTICE)?;
+    }
+    writeln!(
+        file,
+        "Mnemonic: {}",
+        addr.mnemonic
+            .as_ref()
+            .map_or(NO_MNEMONIC_NOTICE, SecretString::expose_secret)
+    )?;
+    if addr.mnemonic.is_some() {
+        writeln!(file, "Mnemonic Language: {}", addr.mnemonic_language.code())?;
+    }
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(file)?;
+
+    Ok(())
+}
+
+/// 写入多链地址到文件（命中任意链时一次性记录所有链）
+pub fn save_multi_address_to_file(
+    filename: &str,
+    multi: &MultiChainAddress,
+    hit_chain: ChainType,
+) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(
+        file,
+        "[VANITY] {} | Hit Chain: {}",
+        timestamp,
+        hit_chain.label()
+    )?;
+    for (chain, addr) in &multi.addresses {
+        writeln!(file, "{} Address: {}", chain.label(), addr.address)?;
+    }
+    for (chain, addr) in &multi.addresses {
+        writeln!(
+            file,
+            "{} Private Key: {}",
+            chain.label(),
+            addr.private_key.expose_secret()
+        )?;
+    }
+    for (chain, addr) in &multi.addresses {
+        if let Some(path) = &addr.derivation_path {
+            writeln!(file, "{} Derivation Path: {}", chain.label(), path)?;
+        }
+    }
+    writeln!(file, "Mnemonic: {}", multi.mnemonic.expose_secret())?;
+    writeln!(file, "Mnemonic Language: {}", multi.language.code())?;
+    if multi.passphrase_used {
+        writeln!(file, "Passphrase: {}", PASSPHRASE_NOTICE)?;
+    }
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(file)?;
+
+    Ok(())
+}
+
+/// 把 TRON/EVM 命中结果加密写入 Keystore V3 文件，明文私钥不落盘
+///
+/// 文件名沿用 geth 格式 `UTC--<时间>--<地址>`，写入目录 dir（不存在时创建），返回文件路径。
+/// Unix 下文件权限为 0600，与 geth 一致。
+pub fn save_keystore_to_file(
+    dir: &str,
+    addr: &VanityAddress,
+    password: &str,
+    kdf: KeystoreKdf,
+) -> std::io::Result<std::path::PathBuf> {
+    let private_key: [u8; 32] = hex::decode(addr.private_key.expose_secret())
+        .ok()
+        .and_then(|key| key.try_into().ok())
+        .ok_or_else(|| std::io::Error::other(VanityError::InvalidPrivateKey))?;
+    let json =
+        encrypt_keystore(addr.chain, &private_key, password, kdf).map_err(std::io::Error::other)?;
+
+    let name_address = addr
+        .chain
+        .chain()
+        .keystore_address(addr)
+        .unwrap_or_default();
+    let timestamp = chrono::Utc::now().format("%Y-%m-%dT%H-%M-%S%.9fZ");
+    std::fs::create_dir_all(dir)?;
+    let path = std::path::Path::new(dir).join(format!("UTC--{}--{}", timestamp, name_address));
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(&path)?.write_all(json.as_bytes())?;
+    Ok(path)
+}
+
+/// Keystore 模式下保存命中：支持 Keystore 的链只写加密文件并返回其路径；
+/// 其余链（SOL）追加到结果文件 filename，但不写助记词，因为同一助记词能恢复 TRON/EVM 私钥。
+pub fn save_hit_with_keystore(
+    filename: &str,
+    dir: &str,
+    addr: &VanityAddress,
+    password: &str,
+    kdf: KeystoreKdf,
+) -> std::io::Result<Option<std::path::PathBuf>> {
+    if addr.chain.chain().keystore_address(addr).is_some() {
+        return save_keystore_to_file(dir, addr, password, kdf).map(Some);
+    }
+    let record = VanityAddress {
+        mnemonic: None,
+        ..addr.clone()
+    };
+    save_address_to_file(filename, &record, true).map(|_| None)
+}
+
+/// 把 SOL 命中写成 solana-keygen 密钥对文件 `<dir>/<地址>.json`，可用 `solana config set --keypair` 直接使用
+///
+/// Unix 下文件权限为 0600，与 solana-keygen 一致。
+pub fn save_sol_keypair_to_file(
+    dir: &str,
+    addr: &VanityAddress,
+) -> std::io::Result<std::path::PathBuf> {
+    let json = sol_keypair_json(addr).map_err(std::io::Error::other)?;
+    std::fs::create_dir_all(dir)?;
+    let path = std::path::Path::new(dir).join(format!("{}.json", addr.address));
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(&path)?
+        .write_all(json.expose_secret().as_bytes())?;
+    Ok(path)
+}
+
+/// 打印分离密钥搜索结果（只含部分私钥）
+pub fn print_split_key_result(result: &SplitKeyResult) {
+    use colored::*;
+
+    println!(
+        "{}",
+        "╔════════════════════════════════════════════════════════════╗".bright_yellow()
+    );
+    println!(
+        "{} {}",
+        "║ 分离密钥命中! | Split-Key Match Found! |".bright_yellow(),
+        "".bright_yellow()
+    );
+    println!(
+        "{}",
+        "╠════════════════════════════════════════════════════════════╣".bright_yellow()
+    );
+    println!("{} {}", "链 | Chain:".bright_green(), result.chain.label());
+    println!(
+        "{} {}",
+        "地址 | Address:".bright_green(),
+        result.address.bright_cyan()
+    );
+    println!(
+        "{} {}",
+        "合成公钥 | Combined Public Key:".bright_blue(),
+        result.public_key
+    );
+    println!(
+        "{} {}",
+        "部分私钥 | Partial Private Key:".bright_red(),
+        result.partial_private_key.bright_white()
+    );
+    println!(
+        "{}",
+        "请把部分私钥交给请求方，由请求方执行合成 | Send the partial key to the requester to combine".bright_magenta()
+    );
+    println!(
+        "{}",
+        "╚════════════════════════════════════════════════════════════╝".bright_yellow()
+    );
+}
+
+/// 写入分离密钥搜索结果
+pub fn save_split_key_result_to_file(
+    filename: &str,
+    result: &SplitKeyResult,
+) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(
+        file,
+        "[SPLIT-KEY] {} | Chain: {}",
+        timestamp,
+        result.chain.label()
+    )?;
+    writeln!(file, "Address: {}", result.address)?;
+    writeln!(file, "Combined Public Key: {}", result.public_key)?;
+    writeln!(file, "Partial Private Key: {}", result.partial_private_key)?;
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(file)?;
+
+    Ok(())
+}
+
+/// 写入分离密钥合成结果：secp256k1 私钥与普通命中记录相同；ed25519 标量不是种子，
+/// 记为 `[SPLIT-KEY]` 记录的 `Ed25519 Scalar` 字段，避免被当作种子导入或校验
+pub fn save_combined_split_key_to_file(
+    filename: &str,
+    addr: &VanityAddress,
+) -> std::io::Result<()> {
+    if addr.chain.chain().curve() == KeyCurve::Secp256k1 {
+        return save_address_to_file(filename, addr, true);
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(
+        file,
+        "[SPLIT-KEY] {} | Chain: {}",
+        timestamp,
+        addr.chain.label()
+    )?;
+    writeln!(file, "Address: {}", addr.address)?;
+    writeln!(file, "Ed25519 Scalar: {}", addr.private_key.expose_secret())?;
+    writeln!(file, "Public Key: {}", addr.public_key)?;
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(file)?;
+
+    Ok(())
+}
+
+/// 打印 CREATE2 盐值搜索结果
+pub fn print_create2_result(result: &Create2Result) {
+    use colored::*;
+
+    println!(
+        "{}",
+        "╔════════════════════════════════════════════════════════════╗".bright_yellow()
+    );
+    println!(
+        "{} {}",
+        "║ CREATE2 命中! | CREATE2 Salt Found! |".bright_yellow(),
+        "".bright_yellow()
+    );
+    println!(
+        "{}",
+        "╠════════════════════════════════════════════════════════════╣".bright_yellow()
+    );
+    println!("{} {}", "链 | Chain:".bright_green(), result.chain.label());
+    println!(
+        "{} {}",
+        "合约地址 | Contract Address:".bright_green(),
+        result.address.bright_cyan()
+    );
+    println!(
+        "{} {}",
+        "盐值 | Salt:".bright_magenta(),
+        result.salt.bright_white()
+    );
+    println!("{} {}", "部署者 | Deployer:".bright_blue(), result.deployer);
+    println!(
+        "{} {}",
+        "Init Code Hash:".bright_blue(),
+        result.init_code_hash
+    );
+    println!(
+        "{}",
+        "╚════════════════════════════════════════════════════════════╝".bright_yellow()
+    );
+}
+
+/// 写入 CREATE2 盐值搜索结果
+pub fn save_create2_result_to_file(filename: &str, result: &Create2Result) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(
+        file,
+        "[CREATE2] {} | Chain: {}",
+        timestamp,
+        result.chain.label()
+    )?;
+    writeln!(file, "Contract Address: {}", result.address)?;
+    writeln!(file, "Salt: {}", result.salt)?;
+    writeln!(file, "Deployer: {}", result.deployer)?;
+    writeln!(file, "Init Code Hash: {}", result.init_code_hash)?;
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(file)?;
+
+    Ok(())
+}
+
+/// 打印 CREATE 合约地址命中：先列出合约地址与 nonce，再列出部署账户的密钥
+pub fn print_contract_hit(hit: &ContractHit) {
+    use colored::*;
 
     println!(
         "{} {}",
-        "TRON 地址:".bright_green(),
-        multi.tron.address.bright_cyan()
-    );
-    println!(
-        "{} {}",
-        "EVM 地址:".bright_green(),
-        multi.evm.address.bright_cyan()
+        "合约地址 | Contract Address:".bright_green(),
+        hit.contract_address.bright_cyan()
     );
     println!(
         "{} {}",
-        "SOL 地址:".bright_green(),
-        multi.sol.address.bright_cyan()
+        "部署 nonce | Nonce:".bright_magenta(),
+        hit.nonce.to_string().bright_white()
     );
+    println!("{}", "部署账户 (EOA) | Deployer account:".bright_green());
+    print_address(&hit.deployer, true);
+}
+
+/// 写入 CREATE 合约地址命中
+pub fn save_contract_hit_to_file(filename: &str, hit: &ContractHit) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    let deployer = &hit.deployer;
+
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(
+        file,
+        "[CREATE] {} | Chain: {}",
+        timestamp,
+        deployer.chain.label()
+    )?;
+    writeln!(file, "Contract Address: {}", hit.contract_address)?;
+    writeln!(file, "Nonce: {}", hit.nonce)?;
+    writeln!(file, "Address: {}", deployer.address)?;
+    writeln!(
+        file,
+        "Private Key: {}",
+        deployer.private_key.expose_secret()
+    )?;
+    writeln!(file, "Public Key: {}", deployer.public_key)?;
+    if let Some(path) = &deployer.derivation_path {
+        writeln!(file, "Derivation Path: {}", path)?;
+    }
+    if deployer.passphrase_used {
+        writeln!(file, "Passphrase: {}", PASSPHRASE_NOTICE)?;
+    }
+    writeln!(
+        file,
+        "Mnemonic: {}",
+        deployer
+            .mnemonic
+            .as_ref()
+            .map_or(NO_MNEMONIC_NOTICE, SecretString::expose_secret)
+    )?;
+    if deployer.mnemonic.is_some() {
+        writeln!(
+            file,
+            "Mnemonic Language: {}",
+            deployer.mnemonic_language.code()
+        )?;
+    }
+    writeln!(
+        file,
+        "═══════════════════════════════════════════════════════════"
+    )?;
+    writeln!(file)?;
+
+    Ok(())
+}
+
+/// 单条记录的校验结果
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum VerifyStatus {
+    /// 地址、公钥、私钥与助记词重新派生的结果全部一致
+    Ok,
+    /// 重新派生的结果与记录不一致，附带不一致的字段
+    Mismatch(String),
+    /// 记录无法解析或无法校验（缺少字段、格式错误、需要 BIP39 密码等）
+    Unparseable(String),
+}
+
+impl std::fmt::Display for VerifyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyStatus::Ok => write!(f, "OK"),
+            VerifyStatus::Mismatch(reason) => write!(f, "MISMATCH: {}", reason),
+            VerifyStatus::Unparseable(reason) => write!(f, "UNPARSEABLE: {}", reason),
+        }
+    }
+}
+
+/// 结果文件中一条记录的校验报告
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    /// 记录起始行号（从 1 开始）
+    pub line: usize,
+    /// 记录的标记行，如 `[VANITY] 2025-12-07 10:30:45 | Chain: TRON`
+    pub header: String,
+    pub status: VerifyStatus,
+}
+
+/// 记录中一条链的密钥字段
+struct RecordedKey<'a> {
+    chain: ChainType,
+    address: &'a str,
+    private_key: &'a str,
+    public_key: Option<&'a str>,
+    derivation_path: Option<&'a str>,
+}
+
+/// 读取并校验 `save_address_to_file` / `save_multi_address_to_file` / `save_contract_hit_to_file` 写入的结果文件
+///
+/// passphrase 用于标记了 `Passphrase:` 的记录；未标记的记录始终按无密码派生。
+pub fn verify_file(filename: &str, passphrase: Option<&str>) -> std::io::Result<Vec<VerifyReport>> {
+    Ok(verify_records(
+        &std::fs::read_to_string(filename)?,
+        passphrase,
+    ))
+}
+
+/// 按分隔线拆分记录并逐条校验
+pub fn verify_records(content: &str, passphrase: Option<&str>) -> Vec<VerifyReport> {
+    let mut reports = Vec::new();
+    let mut record: Option<(usize, Vec<&str>)> = None;
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim_end();
+        if line.starts_with('═') {
+            match record.take() {
+                Some((start, lines)) => reports.push(verify_record(start, &lines, passphrase)),
+                None => record = Some((number + 2, Vec::new())),
+            }
+        } else if let Some((_, lines)) = record.as_mut() {
+            lines.push(line);
+        }
+    }
+    if let Some((start, _)) = record {
+        reports.push(VerifyReport {
+            line: start,
+            header: String::new(),
+            status: VerifyStatus::Unparseable("记录不完整 | truncated record".to_string()),
+        });
+    }
+    reports
+}
+
+fn verify_record(line: usize, lines: &[&str], passphrase: Option<&str>) -> VerifyReport {
+    let header = lines.first().copied().unwrap_or_default();
+    VerifyReport {
+        line,
+        header: header.to_string(),
+        status: check_record(header, lines.get(1..).unwrap_or_default(), passphrase)
+            .err()
+            .unwrap_or(VerifyStatus::Ok),
+    }
+}
+
+fn unparseable(reason: &str) -> VerifyStatus {
+    VerifyStatus::Unparseable(reason.to_string())
+}
+
+fn check_record(
+    header: &str,
+    lines: &[&str],
+    passphrase: Option<&str>,
+) -> Result<(), VerifyStatus> {
+    let mut fields = BTreeMap::new();
+    for line in lines.iter().filter(|line| !line.trim().is_empty()) {
+        let (key, value) = line
+            .split_once(": ")
+            .ok_or_else(|| unparseable(&format!("无法解析的行 | bad line: {}", line)))?;
+        fields.insert(key.trim(), value.trim());
+    }
+
+    let mnemonic = match fields.get("Mnemonic") {
+        Some(&NO_MNEMONIC_NOTICE) | None => None,
+        Some(&mnemonic) => Some(mnemonic),
+    };
+    // 未标记使用密码的记录按无密码派生；标记了但未提供密码时无法校验助记词
+    let passphrase = match (fields.contains_key("Passphrase"), passphrase) {
+        (false, _) => "",
+        (true, Some(passphrase)) => passphrase,
+        (true, None) if mnemonic.is_some() => {
+            return Err(unparseable(
+                "记录使用了 BIP39 密码，请提供密码 | passphrase required",
+            ))
+        }
+        (true, None) => "",
+    };
+    if let (Some(mnemonic), Some(code)) = (mnemonic, fields.get("Mnemonic Language")) {
+        let language = MnemonicLanguage::from_code(code).ok_or_else(|| {
+            unparseable(&format!("未知的助记词语言 | unknown language: {}", code))
+        })?;
+        if Mnemonic::parse_in(language.to_bip39(), mnemonic).is_err() {
+            return Err(VerifyStatus::Mismatch(format!(
+                "助记词语言 | mnemonic language {}",
+                code
+            )));
+        }
+    }
+
+    let tag = header.split_whitespace().next().unwrap_or_default();
+    match tag {
+        "[VANITY]" | "[NORMAL]" if header.contains("| Hit Chain:") => {
+            let mnemonic = mnemonic.ok_or_else(|| unparseable("缺少助记词 | missing mnemonic"))?;
+            let mut checked = 0;
+            for chain in ChainType::all() {
+                let field = |name: &str| {
+                    fields
+                        .get(format!("{} {}", chain.label(), name).as_str())
+                        .copied()
+                };
+                let Some(address) = field("Address") else {
+                    continue;
+                };
+                let private_key = field("Private Key").ok_or_else(|| {
+                    unparseable(&format!("缺少 {} 私钥 | missing private key", chain))
+                })?;
+                let recorded = RecordedKey {
+                    chain,
+                    address,
+                    private_key,
+                    public_key: None,
+                    derivation_path: field("Derivation Path"),
+                };
+                check_recorded_key(&recorded, Some(mnemonic), passphrase)?;
+                checked += 1;
+            }
+            if checked == 0 {
+                return Err(unparseable("没有地址字段 | no addresses"));
+            }
+            Ok(())
+        }
+        "[VANITY]" | "[NORMAL]" | "[CREATE]" => {
+            let field = |name: &str| {
+                fields
+                    .get(name)
+                    .copied()
+                    .ok_or_else(|| unparseable(&format!("缺少字段 | missing field: {}", name)))
+            };
+            let address = field("Address")?;
+            let chain = match header.split_once("| Chain: ") {
+                Some((_, label)) => ChainType::from_label(label.trim())
+                    .ok_or_else(|| unparseable(&format!("未知的链 | unknown chain: {}", label)))?,
+                // 早期版本的记录没有链名，按地址格式推断
+                None if address.starts_with("0x") => ChainType::Evm,
+                None if address.starts_with('T') && address.len() == 34 => ChainType::Tron,
+                None => ChainType::Sol,
+            };
+            let recorded = RecordedKey {
+                chain,
+                address,
+                private_key: field("Private Key")?,
+                public_key: Some(field("Public Key")?),
+                derivation_path: fields.get("Derivation Path").copied(),
+            };
+            check_recorded_key(&recorded, mnemonic, passphrase)?;
+
+            if tag == "[CREATE]" {
+                let nonce: u64 = field("Nonce")?
+                    .parse()
+                    .map_err(|_| unparseable("nonce 无效 | bad nonce"))?;
+                let sender =
+                    parse_account(chain, address).map_err(|e| unparseable(&e.to_string()))?;
+                if create_contract_address(&sender, nonce) != field("Contract Address")? {
+                    return Err(VerifyStatus::Mismatch(
+                        "合约地址 | contract address".to_string(),
+                    ));
+                }
+            }
+            Ok(())
+        }
+        // 分离密钥合成的 ed25519 标量：标量 × 基点应得到记录的公钥与地址
+        "[SPLIT-KEY]" if fields.contains_key("Ed25519 Scalar") => {
+            let field = |name: &str| {
+                fields
+                    .get(name)
+                    .copied()
+                    .ok_or_else(|| unparseable(&format!("缺少字段 | missing field: {}", name)))
+            };
+            let chain = header
+                .split_once("| Chain: ")
+                .and_then(|(_, label)| ChainType::from_label(label.trim()))
+                .ok_or_else(|| unparseable("未知的链 | unknown chain"))?;
+            let scalar = hex::decode(field("Ed25519 Scalar")?)
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .and_then(Ed25519Scalar::from_canonical_bytes)
+                .ok_or_else(|| unparseable("标量无效 | invalid scalar"))?;
+            let from_scalar =
+                ed25519_scalar_address(chain, &scalar).map_err(|e| unparseable(&e.to_string()))?;
+            if from_scalar.address != field("Address")? {
+                return Err(VerifyStatus::Mismatch(format!(
+                    "{} 标量与地址不一致 | address from scalar",
+                    chain
+                )));
+            }
+            if !from_scalar
+                .public_key
+                .eq_ignore_ascii_case(field("Public Key")?)
+            {
+                return Err(VerifyStatus::Mismatch(format!(
+                    "{} 公钥 | public key",
+                    chain
+                )));
+            }
+            Ok(())
+        }
+        _ => Err(unparseable(&format!(
+            "不支持的记录类型 | unsupported record: {}",
+            tag
+        ))),
+    }
+}
+
+/// 校验一条链的私钥、公钥与地址，有助记词时再按记录的路径（缺失时为默认路径）重新派生
+fn check_recorded_key(
+    recorded: &RecordedKey,
+    mnemonic: Option<&str>,
+    passphrase: &str,
+) -> Result<(), VerifyStatus> {
+    let chain = recorded.chain;
+    let mismatch = |field: &str| VerifyStatus::Mismatch(format!("{} {}", chain, field));
+
+    let private_key: [u8; 32] = hex::decode(recorded.private_key)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| unparseable(&format!("{} 私钥格式错误 | bad private key", chain)))?;
+    let from_key = chain
+        .chain()
+        .address_from_private_key(&private_key, None)
+        .map_err(|_| mismatch("私钥无效 | invalid private key"))?;
+    if from_key.address != recorded.address {
+        return Err(mismatch("私钥与地址不一致 | address from private key"));
+    }
+    if recorded
+        .public_key
+        .is_some_and(|public_key| !public_key.eq_ignore_ascii_case(&from_key.public_key))
+    {
+        return Err(mismatch("公钥 | public key"));
+    }
+
+    if let Some(mnemonic) = mnemonic {
+        let path = recorded
+            .derivation_path
+            .map_or_else(|| bip44_path(chain, 0, 0), str::to_string);
+        let derived = derive_address_from_path(mnemonic, passphrase, chain, &path)
+            .map_err(|e| VerifyStatus::Mismatch(format!("{} 助记词 | mnemonic: {}", chain, e)))?;
+        if derived.private_key.expose_secret() != recorded.private_key.to_lowercase() {
+            return Err(mismatch("助记词派生的私钥 | private key from mnemonic"));
+        }
+        if derived.address != recorded.address {
+            return Err(mismatch("助记词派生的地址 | address from mnemonic"));
+        }
+    }
+    Ok(())
+}
+
+/// 获取十六进制字符串
+pub fn hex_encode(data: &[u8]) -> String {
+    hex::encode(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tron_address_generation() {
+        let addr = generate_tron_address().unwrap();
+        assert!(addr.address.starts_with('T'));
+        assert_eq!(addr.private_key.expose_secret().len(), 64);
+    }
+
+    #[test]
+    fn test_evm_address_generation() {
+        let addr = generate_evm_address().unwrap();
+        assert!(addr.address.starts_with("0x"));
+        assert_eq!(addr.address.len(), 42);
+    }
+
+    #[test]
+    fn test_sol_address_generation() {
+        let addr = generate_sol_address().unwrap();
+        assert!(addr.address.len() >= 32); // Solana 地址 Base58 长度不固定但>=32
+    }
+
+    #[test]
+    fn test_vanity_detection() {
+        assert!(is_vanity_address("T1234567890aaa", &[]));
+        assert!(is_vanity_address("0xabc1111", &[]));
+        assert!(!is_vanity_address("Taaa1234567890", &[]));
+    }
+
+    #[test]
+    fn test_custom_pattern_detection() {
+        let patterns = &["lucky"];
+        assert!(is_vanity_address("TAbCDEFlucky", patterns));
+        assert!(!is_vanity_address("luckyABC", patterns));
+    }
+
+    #[test]
+    fn test_known_mnemonic_addresses() {
+        let mnemonic = "scissors inch embody vapor garment panther cinnamon theme first coast panda brand";
+        let multi = generate_from_mnemonic_all(mnemonic).unwrap();
+
+        assert_eq!(
+            multi.addresses[&ChainType::Evm].address,
+            "0x1D2F71D84cB6fE09B06F86F5bf18e498526a7Fb1"
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Tron].address,
+            "TGu44ECEQD9YnG7gkV9paBpbKgKwQnCNN5"
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Sol].address,
+            "3Xa9gJdvWpuSnUyAs34EhFVzA1Lk8Mjs8LYNRnWVWonS"
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_generation() {
+        let addr = generate_tron_address().unwrap();
+        let words: Vec<&str> = addr
+            .mnemonic
+            .as_ref()
+            .unwrap()
+            .expose_secret()
+            .split_whitespace()
+            .collect();
+        assert_eq!(words.len(), 12);
+    }
+
+    #[test]
+    fn test_raw_key_mode() {
+        for chain in [ChainType::Tron, ChainType::Evm, ChainType::Sol] {
+            let addr = generate_vanity_address_with_mode(chain, SearchMode::RawKey).unwrap();
+            assert_eq!(addr.chain, chain);
+            assert!(addr.mnemonic.is_none());
+            assert_eq!(addr.private_key.expose_secret().len(), 64);
+        }
+
+        // 裸私钥结果必须能由私钥重新推出同一地址
+        let tron = generate_raw_tron_address().unwrap();
+        let key: [u8; 32] = hex::decode(tron.private_key.expose_secret())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let public_key = private_key_to_public_key(&key).unwrap();
+        assert_eq!(
+            public_key_to_tron_address(&public_key).unwrap(),
+            tron.address
+        );
+        assert_eq!(hex::encode(&public_key), tron.public_key);
+    }
+
+    #[test]
+    fn test_key_walker_matches_fresh_derivation() {
+        let start = generate_private_key();
+        let walker = Secp256k1KeyWalker::new(&start).unwrap();
+
+        let mut expected = Scalar::from_repr(start.into()).unwrap();
+        for (private_key, public_key) in walker.take(16) {
+            assert_eq!(private_key, <[u8; 32]>::from(expected.to_bytes()));
+            assert_eq!(public_key, private_key_to_public_key(&private_key).unwrap());
+            expected += Scalar::ONE;
+        }
+
+        assert!(Secp256k1KeyWalker::new(&[0u8; 32]).is_err());
+        assert!(Secp256k1KeyWalker::new(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn test_batch_public_keys() {
+        let private_keys: Vec<[u8; 32]> = (0..32).map(|_| generate_private_key()).collect();
+        let batched = batch_private_keys_to_public_keys(&private_keys).unwrap();
+        for (key, public_key) in private_keys.iter().zip(&batched) {
+            assert_eq!(public_key, &private_key_to_public_key(key).unwrap());
+        }
+
+        let mut walker = Secp256k1KeyWalker::random();
+        let mut stepped = walker.clone();
+        for (private_key, public_key) in walker.next_batch(32) {
+            assert_eq!(stepped.next().unwrap(), (private_key, public_key.clone()));
+            assert_eq!(public_key, private_key_to_public_key(&private_key).unwrap());
+        }
+        assert_eq!(walker.current_private_key(), stepped.current_private_key());
+    }
+
+    #[test]
+    fn test_candidate_batch() {
+        for mode in [SearchMode::RawKey, SearchMode::Incremental] {
+            let mut generator = CandidateGenerator::new(mode, &[ChainType::Tron, ChainType::Evm]);
+            let batch = generator.next_batch(16).unwrap();
+            assert_eq!(batch.len(), 16);
+            for candidates in batch {
+                let key: [u8; 32] = hex::decode(candidates[0].private_key.expose_secret())
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                assert_eq!(
+                    candidates[0].address,
+                    tron_address_from_private_key(&key, None).unwrap().address
+                );
+                assert_eq!(
+                    candidates[1].address,
+                    evm_address_from_private_key(&key, None).unwrap().address
+                );
+            }
+
+            let mut generator = CandidateGenerator::new(mode, &[ChainType::Sol]);
+            let batch = generator.next_batch(16).unwrap();
+            assert_eq!(batch.len(), 16);
+            assert!(batch
+                .iter()
+                .all(|candidates| candidates.len() == 1 && candidates[0].chain == ChainType::Sol));
+        }
+    }
+
+    #[test]
+    fn test_incremental_candidates() {
+        let mut generator =
+            CandidateGenerator::new(SearchMode::Incremental, &[ChainType::Tron, ChainType::Evm]);
+
+        for _ in 0..8 {
+            let candidates = generator.next_candidates().unwrap();
+            assert_eq!(candidates[0].private_key, candidates[1].private_key);
+
+            // 命中时报告的私钥必须与重新派生的地址一致
+            for addr in candidates {
+                let key: [u8; 32] = hex::decode(addr.private_key.expose_secret())
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                let fresh = match addr.chain {
+                    ChainType::Tron => tron_address_from_private_key(&key, None).unwrap(),
+                    _ => evm_address_from_private_key(&key, None).unwrap(),
+                };
+                assert_eq!(fresh.address, addr.address);
+                assert_eq!(fresh.public_key, addr.public_key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hd_scan_matches_bip44_derivation() {
+        let chains = [ChainType::Tron, ChainType::Evm, ChainType::Sol];
+        let mut generator = CandidateGenerator::new(SearchMode::HdScan, &chains);
+        generator.set_hd_range(HdScanRange {
+            accounts: 2,
+            indices: 3,
+        });
+
+        let batch = generator.next_batch(6).unwrap();
+        let mnemonic = batch[0][0]
+            .mnemonic
+            .as_ref()
+            .unwrap()
+            .expose_secret()
+            .to_string();
+
+        // 账户 0 的 3 个索引包含 SOL，账户 1 只有 TRON/EVM
+        assert_eq!(batch[2].len(), 3);
+        assert_eq!(batch[3].len(), 2);
+
+        for (position, candidates) in batch.iter().enumerate() {
+            let (account, index) = (position as u32 / 3, position as u32 % 3);
+            for addr in candidates {
+                let fresh = derive_address_at(&mnemonic, "", addr.chain, account, index).unwrap();
+                assert_eq!(
+                    addr.mnemonic.as_ref().map(SecretString::expose_secret),
+                    Some(mnemonic.as_str())
+                );
+                assert_eq!(fresh.address, addr.address);
+                assert_eq!(fresh.private_key, addr.private_key);
+                assert_eq!(fresh.derivation_path, addr.derivation_path);
+            }
+        }
+
+        // 扫完后换新助记词
+        let next = generator.next_candidates().unwrap();
+        assert_ne!(
+            next[0].mnemonic.as_ref().map(SecretString::expose_secret),
+            Some(mnemonic.as_str())
+        );
+        assert_eq!(
+            next[0].derivation_path.as_deref(),
+            Some("m/44'/195'/0'/0/0")
+        );
+    }
+
+    #[test]
+    fn test_derive_address_at_index_zero() {
+        let mnemonic =
+            "scissors inch embody vapor garment panther cinnamon theme first coast panda brand";
+        let multi = generate_from_mnemonic_all(mnemonic).unwrap();
+        assert_eq!(
+            derive_address_at(mnemonic, "", ChainType::Tron, 0, 0)
+                .unwrap()
+                .address,
+            multi.addresses[&ChainType::Tron].address
+        );
+        assert_eq!(
+            derive_address_at(mnemonic, "", ChainType::Evm, 0, 0)
+                .unwrap()
+                .address,
+            multi.addresses[&ChainType::Evm].address
+        );
+        assert_eq!(
+            derive_address_at(mnemonic, "", ChainType::Sol, 0, 0)
+                .unwrap()
+                .address,
+            multi.addresses[&ChainType::Sol].address
+        );
+    }
+
+    #[test]
+    fn test_split_key_combines_to_reported_address() {
+        for chain in [ChainType::Tron, ChainType::Evm, ChainType::Sol] {
+            let request = generate_split_key_request(chain).unwrap();
+            let public_key = hex::decode(&request.public_key).unwrap();
+            let worker = SplitKeyWorker::new(chain, &public_key).unwrap();
+            let found = worker.next_candidate();
+
+            let requester: [u8; 32] = hex::decode(request.private_key.expose_secret())
+                .unwrap()
+                .try_into()
+                .unwrap();
+            let partial: [u8; 32] = hex::decode(&found.partial_private_key)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            let combined = combine_split_key(chain, &requester, &partial).unwrap();
+
+            assert_eq!(combined.address, found.address);
+            assert_eq!(combined.public_key, found.public_key);
+            assert_ne!(
+                combined.private_key.expose_secret(),
+                found.partial_private_key
+            );
+
+            // 合成私钥必须能独立推出同一地址
+            let key: [u8; 32] = hex::decode(combined.private_key.expose_secret())
+                .unwrap()
+                .try_into()
+                .unwrap();
+            match chain {
+                ChainType::Sol => {
+                    let expanded = ed25519_dalek::ExpandedSecretKey::from_bytes(
+                        &ed25519_scalar_to_expanded_key(&key),
+                    )
+                    .unwrap();
+                    // 用合成私钥签名，必须能被报告的地址（即公钥）验证
+                    let public =
+                        PublicKey::from_bytes(&bs58::decode(&found.address).into_vec().unwrap())
+                            .unwrap();
+                    let signature = expanded.sign(b"split-key", &public);
+                    assert!(public.verify_strict(b"split-key", &signature).is_ok());
+                }
+                ChainType::Tron => {
+                    assert_eq!(
+                        public_key_to_tron_address(&private_key_to_public_key(&key).unwrap())
+                            .unwrap(),
+                        found.address
+                    )
+                }
+                ChainType::Evm => {
+                    assert_eq!(
+                        public_key_to_evm_address(&private_key_to_public_key(&key).unwrap())
+                            .unwrap(),
+                        found.address
+                    )
+                }
+            }
+
+            // SOL 标量单独记为 [SPLIT-KEY] 记录，校验模式按标量核对
+            let file = std::env::temp_dir().join(format!(
+                "tron_vanity_split_combine_{}_{}.txt",
+                chain,
+                std::process::id()
+            ));
+            save_combined_split_key_to_file(file.to_str().unwrap(), &combined).unwrap();
+            let record = std::fs::read_to_string(&file).unwrap();
+            let reports = verify_file(file.to_str().unwrap(), None).unwrap();
+            std::fs::remove_file(&file).unwrap();
+            assert_eq!(reports[0].status, VerifyStatus::Ok);
+            assert_eq!(
+                record.contains("[SPLIT-KEY]") && record.contains("Ed25519 Scalar: "),
+                chain == ChainType::Sol
+            );
+            assert_eq!(record.contains("Private Key: "), chain != ChainType::Sol);
+        }
+    }
+
+    #[test]
+    fn test_split_key_rejects_invalid_public_key() {
+        assert!(SplitKeyWorker::new(ChainType::Tron, &[0x04; 65]).is_err());
+        assert!(SplitKeyWorker::new(ChainType::Sol, &[0u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_generate_candidates() {
+        let chains = [ChainType::Tron, ChainType::Sol];
+
+        let candidates = generate_candidates(SearchMode::Mnemonic, &chains).unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].mnemonic, candidates[1].mnemonic);
+        let multi =
+            generate_from_mnemonic_all(candidates[0].mnemonic.as_ref().unwrap().expose_secret())
+                .unwrap();
+        assert_eq!(
+            multi.addresses[&ChainType::Tron].address,
+            candidates[0].address
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Sol].address,
+            candidates[1].address
+        );
+
+        let candidates = generate_candidates(SearchMode::RawKey, &chains).unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates.iter().all(|c| c.mnemonic.is_none()));
+    }
+
+    #[test]
+    fn test_invalid_input_returns_error() {
+        assert!(matches!(
+            generate_from_mnemonic_all("not a valid mnemonic"),
+            Err(VanityError::InvalidMnemonic(_))
+        ));
+        assert!(matches!(
+            private_key_to_public_key(&[0u8; 32]),
+            Err(VanityError::InvalidPrivateKey)
+        ));
+        assert!(matches!(
+            public_key_to_tron_address(&[0x02; 33]),
+            Err(VanityError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn test_secrets_are_redacted_in_debug() {
+        let addr = generate_tron_address().unwrap();
+        let debug = format!("{:?}", addr);
+        assert!(!debug.contains(addr.private_key.expose_secret()));
+        assert!(!debug.contains(addr.mnemonic.as_ref().unwrap().expose_secret()));
+        assert!(debug.contains("[REDACTED]"));
+
+        let multi =
+            generate_from_mnemonic_all(addr.mnemonic.as_ref().unwrap().expose_secret()).unwrap();
+        let debug = format!("{:?}", multi);
+        assert!(!debug.contains(multi.mnemonic.expose_secret()));
+        assert!(!debug.contains(multi.addresses[&ChainType::Evm].private_key.expose_secret()));
+        assert_eq!(
+            multi.addresses[&ChainType::Tron].private_key,
+            addr.private_key
+        );
+    }
+
+    #[test]
+    fn test_pattern_parsing() {
+        let patterns = parse_patterns("8888, ^abc ,*lucky*,@2:99,,").unwrap();
+        assert_eq!(
+            patterns,
+            vec![
+                Pattern::new(MatchKind::Suffix, "8888"),
+                Pattern::new(MatchKind::Prefix, "abc"),
+                Pattern::new(MatchKind::Contains, "lucky"),
+                Pattern::new(MatchKind::Position(2), "99"),
+            ]
+        );
+        for pattern in &patterns {
+            assert_eq!(&Pattern::parse(&pattern.to_string()).unwrap(), pattern);
+        }
+
+        for invalid in ["^", "**", "@x:12", "@3", "@1:"] {
+            assert!(matches!(
+                Pattern::parse(invalid),
+                Err(VanityError::InvalidPattern(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_pattern_modes() {
+        let tron = "TLucky99xyz8888";
+        assert!(Pattern::parse("8888")
+            .unwrap()
+            .matches(ChainType::Tron, tron));
+        assert!(Pattern::parse("^lucky")
+            .unwrap()
+            .matches(ChainType::Tron, tron));
+        assert!(!Pattern::parse("^T").unwrap().matches(ChainType::Tron, tron));
+        assert!(Pattern::parse("*99X*")
+            .unwrap()
+            .matches(ChainType::Tron, tron));
+        assert!(Pattern::parse("@5:99")
+            .unwrap()
+            .matches(ChainType::Tron, tron));
+        assert!(!Pattern::parse("@4:99")
+            .unwrap()
+            .matches(ChainType::Tron, tron));
+        assert!(!Pattern::parse("@40:99")
+            .unwrap()
+            .matches(ChainType::Tron, tron));
+
+        let evm = "0xdead00000000000000000000000000000000beef";
+        assert!(Pattern::parse("^dead")
+            .unwrap()
+            .matches(ChainType::Evm, evm));
+        assert!(!Pattern::parse("^0x").unwrap().matches(ChainType::Evm, evm));
+        assert!(Pattern::parse("@0:DEAD")
+            .unwrap()
+            .matches(ChainType::Evm, evm));
+
+        assert!(matches_patterns(ChainType::Tron, "T1234567890aaa", &[]));
+        assert!(!matches_patterns(
+            ChainType::Tron,
+            tron,
+            &parse_patterns("^8888,7777").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_case_sensitive_patterns() {
+        let pattern = Pattern::parse("!^AAAA").unwrap();
+        assert_eq!(pattern, Pattern::case_sensitive(MatchKind::Prefix, "AAAA"));
+        assert_eq!(pattern.to_string(), "!^AAAA");
+        assert!(pattern.matches(ChainType::Tron, "TAAAAxyz"));
+        assert!(!pattern.matches(ChainType::Tron, "TaaaAxyz"));
+        assert!(Pattern::parse("^AAAA")
+            .unwrap()
+            .matches(ChainType::Tron, "TaaaAxyz"));
+
+        // EVM 区分大小写时按 EIP-55 校验和匹配
+        let evm = "0x1D2F71D84cB6fE09B06F86F5bf18e498526a7Fb1";
+        assert!(Pattern::parse("!^1D2F")
+            .unwrap()
+            .matches(ChainType::Evm, evm));
+        assert!(!Pattern::parse("!^1d2f")
+            .unwrap()
+            .matches(ChainType::Evm, evm));
+        assert!(Pattern::parse("^1d2f")
+            .unwrap()
+            .matches(ChainType::Evm, evm));
+        assert!(Pattern::parse("!7Fb1")
+            .unwrap()
+            .matches(ChainType::Evm, evm));
+    }
+
+    #[test]
+    fn test_pattern_difficulty() {
+        let close = |a: f64, b: f64| (a - b).abs() / b < 1e-9;
+
+        assert!(close(
+            Pattern::parse("8888").unwrap().difficulty(ChainType::Tron),
+            58f64.powi(4)
+        ));
+        assert!(close(
+            Pattern::parse("!AAAA").unwrap().difficulty(ChainType::Tron),
+            58f64.powi(4)
+        ));
+        assert!(close(
+            Pattern::parse("AAAA").unwrap().difficulty(ChainType::Tron),
+            29f64.powi(4)
+        ));
+        assert!(close(
+            Pattern::parse("ab").unwrap().difficulty(ChainType::Evm),
+            256.0
+        ));
+        assert!(close(
+            Pattern::parse("!ab").unwrap().difficulty(ChainType::Evm),
+            1024.0
+        ));
+        assert!(close(
+            Pattern::parse("!12").unwrap().difficulty(ChainType::Evm),
+            256.0
+        ));
+
+        assert!(Pattern::parse("xyz")
+            .unwrap()
+            .difficulty(ChainType::Evm)
+            .is_infinite());
+        assert!(Pattern::parse("!0OIl")
+            .unwrap()
+            .difficulty(ChainType::Sol)
+            .is_infinite());
+        assert!(Pattern::parse("@39:12")
+            .unwrap()
+            .difficulty(ChainType::Evm)
+            .is_infinite());
+    }
+
+    #[test]
+    fn test_pattern_alphabet_validation() {
+        assert_eq!(tron_leading_chars(), "9ABCDEFGHJKLMNPQRSTUVWXYZ");
+
+        let err = validate_pattern(ChainType::Tron, &Pattern::parse("0OIl").unwrap()).unwrap_err();
+        assert!(matches!(
+            err,
+            VanityError::ImpossiblePattern {
+                character: Some('0'),
+                ..
+            }
+        ));
+        let err = validate_pattern(ChainType::Sol, &Pattern::parse("!OIl").unwrap()).unwrap_err();
+        assert!(matches!(
+            err,
+            VanityError::ImpossiblePattern {
+                character: Some('O'),
+                ..
+            }
+        ));
+        assert!(validate_pattern(ChainType::Sol, &Pattern::parse("OIl").unwrap()).is_ok());
+
+        let err = validate_pattern(ChainType::Evm, &Pattern::parse("xyz").unwrap()).unwrap_err();
+        assert!(matches!(
+            err,
+            VanityError::ImpossiblePattern {
+                character: Some('x'),
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("0123456789abcdef"));
 
-    println!(
-        "{} {}",
-        "TRON 私钥:".bright_red(),
-        multi.tron.private_key.bright_red()
-    );
-    println!(
-        "{} {}",
-        "EVM 私钥:".bright_red(),
-        multi.evm.private_key.bright_red()
-    );
-    println!(
-        "{} {}",
-        "SOL 私钥:".bright_red(),
-        multi.sol.private_key.bright_red()
-    );
+        // TRON 的 T 之后只能是 9..Z
+        assert!(validate_pattern(ChainType::Tron, &Pattern::parse("^8888").unwrap()).is_err());
+        assert!(validate_pattern(ChainType::Tron, &Pattern::parse("^a").unwrap()).is_ok());
+        assert!(validate_pattern(ChainType::Tron, &Pattern::parse("!^a").unwrap()).is_err());
+        assert!(validate_pattern(ChainType::Tron, &Pattern::parse("@1:8888").unwrap()).is_ok());
+        assert!(validate_pattern(ChainType::Tron, &Pattern::parse("@32:88").unwrap()).is_err());
 
-    println!(
-        "{} {}",
-        "助记词 | Mnemonic:".bright_magenta(),
-        multi.mnemonic.bright_white()
-    );
-    println!(
-        "{}",
-        "╚════════════════════════════════════════════════════════════╝".bright_yellow()
-    );
-}
+        // 全部链都不可能才报错，否则只返回警告
+        let chains = [ChainType::Tron, ChainType::Evm];
+        let warnings = check_patterns(&chains, &parse_patterns("xyz,8888").unwrap()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(check_patterns(&chains, &parse_patterns("0O").unwrap()).is_err());
 
-/// 写入文件
-pub fn save_address_to_file(
-    filename: &str,
-    addr: &VanityAddress,
-    is_vanity: bool,
-) -> std::io::Result<()> {
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(filename)?;
+        for _ in 0..32 {
+            let tron = generate_raw_tron_address().unwrap();
+            assert!(tron_leading_chars().contains(&tron.address[1..2]));
+        }
+    }
 
-    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-    let vanity_mark = if is_vanity { "[VANITY]" } else { "[NORMAL]" };
+    #[test]
+    fn test_estimate_difficulty() {
+        let close = |a: f64, b: f64| (a - b).abs() / b < 1e-4;
 
-    writeln!(
-        file,
-        "═══════════════════════════════════════════════════════════"
-    )?;
-    writeln!(
-        file,
-        "{} {} | Chain: {}",
-        vanity_mark,
-        timestamp,
-        addr.chain.label()
-    )?;
-    writeln!(file, "Address: {}", addr.address)?;
-    writeln!(file, "Private Key: {}", addr.private_key)?;
-    writeln!(file, "Public Key: {}", addr.public_key)?;
-    writeln!(file, "Mnemonic: {}", addr.mnemonic)?;
-    writeln!(
-        file,
-        "═══════════════════════════════════════════════════════════"
-    )?;
-    writeln!(file)?;
+        let difficulty = estimate_difficulty(ChainType::Evm, &Pattern::parse("dead").unwrap());
+        assert!(close(difficulty.expected_attempts(), 65536.0));
+        assert!(close(
+            difficulty.attempts_for(0.5),
+            65536.0 * std::f64::consts::LN_2
+        ));
+        assert!(close(
+            difficulty.probability_after(65536),
+            1.0 - (-1.0f64).exp()
+        ));
+        assert_eq!(difficulty.probability_after(0), 0.0);
+        assert!(close(
+            difficulty.eta_seconds(0.9, 0, 1000.0),
+            difficulty.attempts_for(0.9) / 1000.0
+        ));
+        assert_eq!(difficulty.eta_seconds(0.5, 1_000_000, 1000.0), 0.0);
+        assert!(difficulty.eta_seconds(0.5, 0, 0.0).is_infinite());
 
-    Ok(())
-}
+        // TRON 的 T 之后第一位约 23.4 种取值
+        let prefix = estimate_difficulty(ChainType::Tron, &Pattern::parse("!^T").unwrap());
+        assert!(close(
+            prefix.expected_attempts(),
+            256f64.powi(24) / 58f64.powi(32)
+        ));
+        assert!(
+            estimate_difficulty(ChainType::Tron, &Pattern::parse("^8").unwrap())
+                .expected_attempts()
+                .is_infinite()
+        );
 
-/// 写入多链地址到文件（命中任意链时一次性记录三条链）
-pub fn save_multi_address_to_file(
-    filename: &str,
-    multi: &MultiChainAddress,
-    hit_chain: ChainType,
-) -> std::io::Result<()> {
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(filename)?;
+        // 多条链、多个模式合计更容易命中
+        let patterns = parse_patterns("dead,beef").unwrap();
+        let combined = estimate_combined_difficulty(&[ChainType::Evm], &patterns);
+        assert!(close(
+            combined.probability,
+            1.0 - (1.0 - 1.0 / 65536.0f64).powi(2)
+        ));
+        let default_rule = estimate_combined_difficulty(&[ChainType::Evm], &[]);
+        assert!(close(default_rule.expected_attempts(), 256.0));
 
-    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+        assert_eq!(format_eta(42.0), "42s");
+        assert_eq!(format_eta(3_725.0), "1h 2m");
+        assert_eq!(format_eta(f64::INFINITY), "∞");
+    }
 
-    writeln!(
-        file,
-        "═══════════════════════════════════════════════════════════"
-    )?;
-    writeln!(
-        file,
-        "[VANITY] {} | Hit Chain: {}",
-        timestamp,
-        hit_chain.label()
-    )?;
-    writeln!(file, "TRON Address: {}", multi.tron.address)?;
-    writeln!(file, "EVM Address: {}", multi.evm.address)?;
-    writeln!(file, "SOL Address: {}", multi.sol.address)?;
-    writeln!(file, "TRON Private Key: {}", multi.tron.private_key)?;
-    writeln!(file, "EVM Private Key: {}", multi.evm.private_key)?;
-    writeln!(file, "SOL Private Key: {}", multi.sol.private_key)?;
-    writeln!(file, "Mnemonic: {}", multi.mnemonic)?;
-    writeln!(
-        file,
-        "═══════════════════════════════════════════════════════════"
-    )?;
-    writeln!(file)?;
+    #[test]
+    fn test_pattern_matcher() {
+        let patterns = parse_patterns("!^AB,^9x,88,!Zz,*dead*,@2:77").unwrap();
+        let matcher = PatternMatcher::new(&patterns);
 
-    Ok(())
-}
+        assert_eq!(
+            matcher
+                .find(ChainType::Tron, "TABcdef")
+                .unwrap()
+                .to_string(),
+            "!^AB"
+        );
+        assert!(matcher.find(ChainType::Tron, "TabcdeZ").is_none());
+        assert_eq!(
+            matcher.find(ChainType::Tron, "T9Xqq").unwrap().to_string(),
+            "^9x"
+        );
+        assert_eq!(
+            matcher
+                .find(ChainType::Evm, "0x1234cc88")
+                .unwrap()
+                .to_string(),
+            "88"
+        );
+        assert_eq!(
+            matcher.find(ChainType::Sol, "qqqZz").unwrap().to_string(),
+            "!Zz"
+        );
+        assert!(matcher.find(ChainType::Sol, "qqqzz").is_none());
+        assert_eq!(
+            matcher
+                .find(ChainType::Evm, "0x12DEADff")
+                .unwrap()
+                .to_string(),
+            "*dead*"
+        );
+        assert_eq!(
+            matcher
+                .find(ChainType::Evm, "0x1277ff")
+                .unwrap()
+                .to_string(),
+            "@2:77"
+        );
 
-/// 获取十六进制字符串
-pub fn hex_encode(data: &[u8]) -> String {
-    hex::encode(data)
-}
+        // 与逐个模式检查的结果一致，包括默认规则
+        let lucky: Vec<Pattern> = (0..5000)
+            .map(|n| Pattern::new(MatchKind::Suffix, &format!("{:04}", n)))
+            .collect();
+        for patterns in [patterns, lucky, Vec::new()] {
+            let matcher = PatternMatcher::new(&patterns);
+            for _ in 0..200 {
+                for addr in
+                    generate_candidates(SearchMode::RawKey, &[ChainType::Tron, ChainType::Evm])
+                        .unwrap()
+                {
+                    assert_eq!(
+                        matcher.is_match(addr.chain, &addr.address),
+                        matches_patterns(addr.chain, &addr.address, &patterns)
+                    );
+                    if let Some(pattern) = matcher.find(addr.chain, &addr.address) {
+                        assert!(pattern.matches(addr.chain, &addr.address));
+                    }
+                }
+            }
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_beauty_score_features() {
+        let features = |address: &str| {
+            beauty_score(ChainType::Tron, address)
+                .features
+                .into_iter()
+                .map(|(feature, _)| feature)
+                .collect::<Vec<_>>()
+        };
+
+        let score = beauty_score(ChainType::Tron, "TQxyzkqwe8888");
+        assert_eq!(
+            score.features,
+            vec![
+                (BeautyFeature::TrailingRun("8888".to_string()), 40),
+                (BeautyFeature::LuckyNumber("8888".to_string()), 30),
+            ]
+        );
+        assert_eq!(score.score, 70);
+
+        assert!(features("Taaaaxqzkwe").contains(&BeautyFeature::LeadingRun("aaaa".to_string())));
+        assert!(features("Tqxzk520520520k")
+            .contains(&BeautyFeature::RepeatedBlock("520520520".to_string())));
+        assert!(features("Tqxzkwv12321").contains(&BeautyFeature::Palindrome("12321".to_string())));
+        assert!(features("Tqx123456zkwv").contains(&BeautyFeature::Sequence("123456".to_string())));
+        assert!(features("Tqxzkw98765q").contains(&BeautyFeature::Sequence("98765".to_string())));
+        assert!(features("Tqxz1314kwv").contains(&BeautyFeature::LuckyNumber("1314".to_string())));
+        assert!(features("0xqxzkwv").is_empty());
+
+        // EVM 的 0x 不参与评分
+        assert_eq!(
+            beauty_score(ChainType::Evm, "0x000abc").features[0].0,
+            BeautyFeature::LeadingRun("000".to_string())
+        );
+    }
 
     #[test]
-    fn test_tron_address_generation() {
-        let addr = generate_tron_address();
-        assert!(addr.address.starts_with('T'));
-        assert_eq!(addr.private_key.len(), 64);
+    fn test_leading_zero_search() {
+        let mut account = [0xffu8; 20];
+        assert_eq!(count_leading_zeros(&account, ZeroUnit::Nibble), 0);
+        account[0] = 0x00;
+        account[1] = 0x0f;
+        assert_eq!(count_leading_zeros(&account, ZeroUnit::Nibble), 3);
+        assert_eq!(count_leading_zeros(&account, ZeroUnit::Byte), 1);
+        assert_eq!(count_leading_zeros(&[0u8; 20], ZeroUnit::Nibble), 40);
+        assert_eq!(ZeroUnit::Byte.difficulty(2).expected_attempts(), 65536.0);
+
+        let mut search = LeadingZeroSearch::new(ZeroUnit::Nibble, 2);
+        let mut generator = CandidateGenerator::new(SearchMode::Incremental, &[ChainType::Evm]);
+        while !search.is_complete() {
+            let hit = generator
+                .next_leading_zero_batch(256, ZeroUnit::Nibble)
+                .unwrap()
+                .unwrap();
+            search.offer(hit);
+        }
+
+        // 命中结果可由私钥重新派生，且前导零计数发生在 EIP-55 编码之前
+        let best = search.best().unwrap();
+        assert!(best.zeros >= 2);
+        assert!(best.address.address.starts_with("0x00"));
+        let private_key: [u8; 32] = hex::decode(best.address.private_key.expose_secret())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let public_key = private_key_to_public_key(&private_key).unwrap();
+        assert_eq!(
+            public_key_to_evm_address(&public_key).unwrap(),
+            best.address.address
+        );
+
+        // 不比当前最好的结果更好时不替换
+        let worse = LeadingZeroHit {
+            zeros: 0,
+            address: best.address.clone(),
+        };
+        assert!(!search.offer(worse));
     }
 
     #[test]
-    fn test_evm_address_generation() {
-        let addr = generate_evm_address();
-        assert!(addr.address.starts_with("0x"));
-        assert_eq!(addr.address.len(), 42);
+    fn test_create2_address() {
+        // EIP-1014 示例 0 与示例 1（initCode = 0x00，salt = 0）
+        let init_code_hash = keccak256(&[0x00]);
+        let salt = [0u8; 32];
+        assert_eq!(
+            create2_address(ChainType::Evm, &[0u8; 20], &salt, &init_code_hash).unwrap(),
+            "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"
+        );
+        let deployer =
+            parse_account(ChainType::Evm, "0xdeadbeef00000000000000000000000000000000").unwrap();
+        assert_eq!(
+            create2_address(ChainType::Evm, &deployer, &salt, &init_code_hash).unwrap(),
+            "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3"
+        );
+
+        // TRON 部署者地址的 Base58Check / hex 两种写法解析一致，校验和错误时报错
+        let tron_deployer = account_to_tron_address(&deployer);
+        assert_eq!(
+            parse_account(ChainType::Tron, &tron_deployer).unwrap(),
+            deployer
+        );
+        assert_eq!(
+            parse_account(ChainType::Tron, &format!("41{}", hex::encode(deployer))).unwrap(),
+            deployer
+        );
+        let mut corrupted = tron_deployer.clone();
+        corrupted.replace_range(
+            5..6,
+            if &tron_deployer[5..6] == "A" {
+                "B"
+            } else {
+                "A"
+            },
+        );
+        assert!(parse_account(ChainType::Tron, &corrupted).is_err());
+        assert!(create2_address(ChainType::Sol, &deployer, &salt, &init_code_hash).is_err());
+
+        assert!(matches!(
+            Create2Miner::new(ChainType::Tron, &tron_deployer, &init_code_hash[..31], &[]),
+            Err(VanityError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            Create2Miner::new(ChainType::Tron, &tron_deployer, &init_code_hash, &[0u8; 25]),
+            Err(VanityError::InvalidParameter(_))
+        ));
+
+        // 搜索结果可由 salt 重新计算，且 salt 保留固定前缀
+        let matcher = PatternMatcher::new(&[Pattern::new(MatchKind::Suffix, "a")]);
+        let mut miner =
+            Create2Miner::new(ChainType::Tron, &tron_deployer, &init_code_hash, &deployer).unwrap();
+        let result = loop {
+            let result = miner.next_candidate();
+            if matcher.is_match(ChainType::Tron, &result.address) {
+                break result;
+            }
+        };
+        assert_eq!(result.deployer, tron_deployer);
+        assert!(result
+            .salt
+            .starts_with(&format!("0x{}", hex::encode(deployer))));
+        let salt: [u8; 32] = hex::decode(&result.salt[2..]).unwrap().try_into().unwrap();
+        let expected = create2_address(ChainType::Tron, &deployer, &salt, &init_code_hash).unwrap();
+        assert_eq!(result.address, expected);
+        assert!(expected.starts_with('T'));
     }
 
     #[test]
-    fn test_sol_address_generation() {
-        let addr = generate_sol_address();
-        assert!(addr.address.len() >= 32); // Solana 地址 Base58 长度不固定但>=32
+    fn test_create_contract_address() {
+        let sender =
+            parse_account(ChainType::Evm, "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0").unwrap();
+        let expected = [
+            "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d",
+            "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8",
+            "0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91",
+            "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c",
+        ];
+        for (nonce, expected) in expected.iter().enumerate() {
+            assert_eq!(
+                create_contract_address(&sender, nonce as u64).to_lowercase(),
+                *expected
+            );
+        }
+
+        // nonce 0 为空字符串，≥ 0x80 时需要长度前缀
+        assert_eq!(rlp_encode_create(&sender, 0)[22..], [0x80]);
+        assert_eq!(rlp_encode_create(&sender, 0x7f)[22..], [0x7f]);
+        assert_eq!(rlp_encode_create(&sender, 0x80)[22..], [0x81, 0x80]);
+        assert_eq!(rlp_encode_create(&sender, 0x0400)[..2], [0xd8, 0x94]);
+
+        // 命中结果的合约地址可由部署账户私钥重新推导
+        let matcher = PatternMatcher::new(&[Pattern::new(MatchKind::Suffix, "a")]);
+        let mut generator = CandidateGenerator::new(SearchMode::RawKey, &[ChainType::Evm]);
+        let hit = loop {
+            if let Some(hit) = generator
+                .next_contract_batch(16, 2, &matcher)
+                .unwrap()
+                .pop()
+            {
+                break hit;
+            }
+        };
+        assert!(hit.nonce <= 2);
+        assert!(hit.contract_address.to_lowercase().ends_with('a'));
+        let private_key: [u8; 32] = hex::decode(hit.deployer.private_key.expose_secret())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let public_key = private_key_to_public_key(&private_key).unwrap();
+        let account = public_key_to_account(&public_key).unwrap();
+        assert_eq!(evm_checksum_address(&account), hit.deployer.address);
+        assert_eq!(
+            create_contract_address(&account, hit.nonce),
+            hit.contract_address
+        );
     }
 
     #[test]
-    fn test_vanity_detection() {
-        assert!(is_vanity_address("T1234567890aaa", &[]));
-        assert!(is_vanity_address("0xabc1111", &[]));
-        assert!(!is_vanity_address("Taaa1234567890", &[]));
+    fn test_chain_registry() {
+        // 注册表顺序必须与 ChainType 声明顺序一致，ChainType::chain 按下标查找
+        for (i, chain) in ChainType::all().enumerate() {
+            assert_eq!(chain as usize, i);
+            assert_eq!(chain.chain().chain_type(), chain);
+        }
+        assert_eq!(
+            ChainType::all().collect::<Vec<_>>(),
+            [ChainType::Tron, ChainType::Evm, ChainType::Sol]
+        );
+
+        // 多链结果按注册表遍历，每条链的结果与单链派生一致
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let multi = generate_from_mnemonic_all(mnemonic).unwrap();
+        assert!(multi.addresses.keys().copied().eq(ChainType::all()));
+        for chain in ChainType::all() {
+            let addr = multi.get(chain).unwrap();
+            assert_eq!(addr.chain, chain);
+            assert_eq!(
+                addr.derivation_path,
+                Some(chain.chain().derivation_path(0, 0))
+            );
+            assert!(addr.address.starts_with(chain.address_prefix()));
+        }
     }
 
     #[test]
-    fn test_custom_pattern_detection() {
-        let patterns = &["lucky"];
-        assert!(is_vanity_address("TAbCDEFlucky", patterns));
-        assert!(!is_vanity_address("luckyABC", patterns));
+    fn test_derivation_path_presets() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        // 默认预设保持原有路径
+        let paths = DerivationPaths::default();
+        assert_eq!(paths.path(ChainType::Evm, 0, 3), "m/44'/60'/0'/0/3");
+        assert_eq!(paths.path(ChainType::Sol, 0, 0), "m/44'/501'/0'");
+        assert_eq!(paths.preset_name(ChainType::Tron), Some("TronLink"));
+
+        // Phantom：m/44'/501'/0'/0'
+        let mut paths = DerivationPaths::default();
+        paths
+            .set_template(ChainType::Sol, "m/44'/501'/{index}'/0'")
+            .unwrap();
+        paths
+            .set_template(ChainType::Evm, "m/44'/60'/{index}'/0/0")
+            .unwrap();
+        assert_eq!(
+            paths.preset_name(ChainType::Sol),
+            Some("Phantom / Solflare")
+        );
+        assert_eq!(paths.preset_name(ChainType::Evm), Some("Ledger Live"));
+        let multi = generate_from_mnemonic_with_options(
+            mnemonic,
+            &MnemonicOptions {
+                paths: paths.clone(),
+                passphrase: None,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            multi.addresses[&ChainType::Sol].address,
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Sol].derivation_path.as_deref(),
+            Some("m/44'/501'/0'/0'")
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Evm].address,
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+
+        // 缺少 {index}、ed25519 非硬化路径、无法解析的路径都拒绝
+        assert!(paths
+            .set_template(ChainType::Evm, "m/44'/60'/0'/0/0")
+            .is_err());
+        assert!(paths
+            .set_template(ChainType::Sol, "m/44'/501'/0'/{index}")
+            .is_err());
+        assert!(paths
+            .set_template(ChainType::Tron, "m/44'/x/{index}")
+            .is_err());
+        assert_eq!(paths.preset_name(ChainType::Evm), Some("Ledger Live"));
+
+        // HD 扫描在自定义路径下的结果与按记录路径重新派生一致
+        paths
+            .set_template(ChainType::Sol, "m/44'/501'/{account}'/{index}'")
+            .unwrap();
+        let chains = [ChainType::Tron, ChainType::Evm, ChainType::Sol];
+        let mut generator = CandidateGenerator::new(SearchMode::HdScan, &chains);
+        generator.set_hd_range(HdScanRange {
+            accounts: 2,
+            indices: 2,
+        });
+        generator.set_mnemonic_options(MnemonicOptions {
+            paths,
+            passphrase: None,
+            ..Default::default()
+        });
+        let mut seen = Vec::new();
+        for addr in generator.next_batch(4).unwrap().into_iter().flatten() {
+            let path = addr.derivation_path.clone().unwrap();
+            let fresh = derive_address_from_path(
+                addr.mnemonic.as_ref().unwrap().expose_secret(),
+                "",
+                addr.chain,
+                &path,
+            )
+            .unwrap();
+            assert_eq!(fresh.address, addr.address);
+            seen.push(path);
+        }
+        // EVM 的 Ledger Live 模板不含 {account}，只在账户 0 上扫描
+        assert!(seen.contains(&"m/44'/60'/1'/0/0".to_string()));
+        assert!(seen.contains(&"m/44'/195'/1'/0/1".to_string()));
+        assert!(seen.contains(&"m/44'/501'/1'/1'".to_string()));
+        assert_eq!(seen.len(), 4 + 2 + 4);
     }
 
     #[test]
-    fn test_known_mnemonic_addresses() {
-        let mnemonic = "scissors inch embody vapor garment panther cinnamon theme first coast panda brand";
-        let multi = generate_from_mnemonic_all(mnemonic);
+    fn test_mnemonic_word_count() {
+        for word_count in WordCount::ALL {
+            let mnemonic = generate_mnemonic_with_words(word_count).unwrap();
+            assert_eq!(mnemonic.split_whitespace().count(), word_count.words());
+            let parsed = Mnemonic::parse(&mnemonic).unwrap();
+            assert_eq!(parsed.word_count(), word_count.words());
+            assert_eq!(parsed.to_entropy().len(), word_count.entropy_bytes());
+            assert_eq!(parsed.to_string(), mnemonic);
+            assert_eq!(WordCount::from_words(word_count.words()), Some(word_count));
+        }
+        assert_eq!(WordCount::from_words(13), None);
+        assert_eq!(generate_mnemonic().unwrap().split_whitespace().count(), 12);
 
-        assert_eq!(multi.evm.address, "0x1D2F71D84cB6fE09B06F86F5bf18e498526a7Fb1");
-        assert_eq!(multi.tron.address, "TGu44ECEQD9YnG7gkV9paBpbKgKwQnCNN5");
-        assert_eq!(multi.sol.address, "3Xa9gJdvWpuSnUyAs34EhFVzA1Lk8Mjs8LYNRnWVWonS");
+        // 搜索时按所选长度生成，命中结果仍可由助记词恢复
+        let options = MnemonicOptions {
+            word_count: WordCount::Words24,
+            ..Default::default()
+        };
+        for mode in [SearchMode::Mnemonic, SearchMode::HdScan] {
+            let mut generator = CandidateGenerator::new(mode, &[ChainType::Tron, ChainType::Sol]);
+            generator.set_mnemonic_options(options.clone());
+            for addr in generator.next_candidates().unwrap() {
+                let mnemonic = addr.mnemonic.as_ref().unwrap().expose_secret();
+                assert_eq!(mnemonic.split_whitespace().count(), 24);
+                let fresh = derive_address_from_path(
+                    mnemonic,
+                    "",
+                    addr.chain,
+                    addr.derivation_path.as_deref().unwrap(),
+                )
+                .unwrap();
+                assert_eq!(fresh.address, addr.address);
+            }
+        }
     }
 
     #[test]
-    fn test_mnemonic_generation() {
-        let addr = generate_tron_address();
-        let words: Vec<&str> = addr.mnemonic.split_whitespace().collect();
-        assert_eq!(words.len(), 12);
+    fn test_mnemonic_languages() {
+        // BIP39 官方日文向量（bip32JP），全角空格分隔
+        let passphrase = "㍍ガバヴァぱばぐゞちぢ十人十色";
+        let japanese = [
+            (
+                "00000000000000000000000000000000",
+                "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら",
+                "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55",
+            ),
+            (
+                "ffffffffffffffffffffffffffffffffffffffffffffffff",
+                "われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　りんご",
+                "f0f738128a65b8d1854d68de50ed97ac1831fc3a978c569e415bbcb431a6a671d4377e3b56abd518daa861676c4da75a19ccb41e00c37d086941e471a4374b95",
+            ),
+        ];
+        for (entropy, mnemonic, seed) in japanese {
+            assert_eq!(
+                mnemonic_language(mnemonic).unwrap(),
+                MnemonicLanguage::Japanese
+            );
+            assert_eq!(
+                hex::encode(parse_mnemonic(mnemonic).unwrap().to_entropy()),
+                entropy
+            );
+            assert_eq!(
+                hex::encode(mnemonic_to_seed(mnemonic, passphrase).unwrap()),
+                seed
+            );
+        }
+
+        // 中文 / 韩文：熵 7f…7f，密码 TREZOR
+        let vectors = [
+            (
+                MnemonicLanguage::SimplifiedChinese,
+                "枪 疫 霉 尝 俩 闹 饿 贤 枪 疫 霉 卿",
+                "816a69d6866891b246b4d33f54d6d2be624470141754396205d039bdd8003949fec4340253dde4c8e11437a181ad992f56d5b976eb9fbe48f4c5e5fec60a27e1",
+            ),
+            (
+                MnemonicLanguage::TraditionalChinese,
+                "槍 疫 黴 嘗 倆 鬧 餓 賢 槍 疫 黴 卿",
+                "f38af46f6bc3222b0f5aa14dd5b8b506e51131510f2450ec9fb52c28617cfa59d436055fe542e25dfa01415639d2171e41796f169f8bbc18516941dfdee8fb72",
+            ),
+            (
+                MnemonicLanguage::Korean,
+                "실장 활동 큰절 흔적 형제 제대로 훈련 한글 실장 활동 큰절 흔히",
+                "e6995bf885f5c64932ca28bbb00bc100a6b89cb6edc987bb05f05f99ae7caf78329029c189834c1cca938000bcf08423da011558a60cf3d90c9035eaaf241b9e",
+            ),
+        ];
+        for (language, mnemonic, seed) in vectors {
+            // 比较熵而不是字符串：词表为 NFKD 形式，源码中的韩文为 NFC 形式
+            let generated = Mnemonic::from_entropy_in(language.to_bip39(), &[0x7f; 16]).unwrap();
+            assert_eq!(generated, parse_mnemonic(mnemonic).unwrap());
+            assert_eq!(parse_mnemonic(mnemonic).unwrap().to_entropy(), [0x7f; 16]);
+            assert_eq!(mnemonic_language(mnemonic).unwrap(), language);
+            assert_eq!(
+                hex::encode(mnemonic_to_seed(mnemonic, "TREZOR").unwrap()),
+                seed
+            );
+            let multi = generate_from_mnemonic_all(mnemonic).unwrap();
+            assert_eq!(multi.language, language);
+            assert!(multi
+                .addresses
+                .values()
+                .all(|addr| addr.mnemonic_language == language));
+        }
+
+        // 只由简繁共同字组成的助记词两种语言都合法，仍可解析
+        let common = "的 的 的 的 的 的 的 的 的 的 的 在";
+        assert_eq!(
+            mnemonic_language(common).unwrap(),
+            MnemonicLanguage::SimplifiedChinese
+        );
+        assert_eq!(
+            hex::encode(mnemonic_to_seed(common, "TREZOR").unwrap()),
+            "7f7c7f91ef81f0fb6a3b95b346c50e6472c1d554f8ba90637bad8afce4a4de87c322c1acafa2f6f5e9a8f9b2d2c40e9d389efdc2adbe4445c21a0939fb39e91f"
+        );
+
+        for language in MnemonicLanguage::ALL {
+            assert_eq!(MnemonicLanguage::from_code(language.code()), Some(language));
+            let mnemonic = generate_mnemonic_in(language, WordCount::Words24).unwrap();
+            let parsed = Mnemonic::parse_in(language.to_bip39(), mnemonic.as_str()).unwrap();
+            assert_eq!(parsed.word_count(), 24);
+            assert!(mnemonic_to_seed(&mnemonic, "").is_ok());
+        }
+
+        // 搜索命中记录所选语言，并可由助记词恢复
+        let options = MnemonicOptions {
+            language: MnemonicLanguage::SimplifiedChinese,
+            ..Default::default()
+        };
+        for mode in [SearchMode::Mnemonic, SearchMode::HdScan] {
+            let mut generator = CandidateGenerator::new(mode, &[ChainType::Evm, ChainType::Sol]);
+            generator.set_mnemonic_options(options.clone());
+            for addr in generator.next_candidates().unwrap() {
+                assert_eq!(addr.mnemonic_language, MnemonicLanguage::SimplifiedChinese);
+                let mnemonic = addr.mnemonic.as_ref().unwrap().expose_secret();
+                let fresh = derive_address_from_path(
+                    mnemonic,
+                    "",
+                    addr.chain,
+                    addr.derivation_path.as_deref().unwrap(),
+                )
+                .unwrap();
+                assert_eq!(fresh.address, addr.address);
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_addresses() {
+        assert_eq!(parse_index_range("", 0..5), Some(0..5));
+        assert_eq!(parse_index_range("7", 0..5), Some(7..8));
+        assert_eq!(parse_index_range(" 0 - 19 ", 0..5), Some(0..20));
+        assert_eq!(parse_index_range("5-2", 0..5), None);
+        assert_eq!(parse_index_range("x", 0..5), None);
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let mut options = MnemonicOptions {
+            passphrase: Some("TREZOR".into()),
+            ..Default::default()
+        };
+        options
+            .paths
+            .set_template(ChainType::Sol, "m/44'/501'/{index}'/0'")
+            .unwrap();
+        let chains = [ChainType::Evm, ChainType::Sol];
+        let derived = derive_addresses(mnemonic, &options, &chains, 0..2, 0..3).unwrap();
+        // SOL 模板不含 {account}，只派生一遍
+        assert_eq!(derived.len(), 2 * 3 + 3);
+        assert_eq!(
+            derived[0].address,
+            "0x9c32F71D4DB8Fb9e1A58B0a80dF79935e7256FA6"
+        );
+        assert_eq!(
+            derived[6].address,
+            "7zSmbu6gKkb6HB7UDPtHYjwCWuBHU1D4TpNZFm4sndQe"
+        );
+        assert_eq!(
+            derived[5].derivation_path.as_deref(),
+            Some("m/44'/60'/1'/0/2")
+        );
+        for addr in &derived {
+            assert!(addr.passphrase_used);
+            let fresh = derive_address_from_path(
+                mnemonic,
+                "TREZOR",
+                addr.chain,
+                addr.derivation_path.as_deref().unwrap(),
+            )
+            .unwrap();
+            assert_eq!(fresh.address, addr.address);
+            assert_eq!(fresh.private_key, addr.private_key);
+        }
+        assert!(derive_addresses("abandon about", &options, &chains, 0..1, 0..1).is_err());
+    }
+
+    #[test]
+    fn test_verify_records() {
+        let file =
+            std::env::temp_dir().join(format!("tron_vanity_verify_{}.txt", std::process::id()));
+        let filename = file.to_str().unwrap();
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let raw = generate_raw_tron_address().unwrap();
+        let with_passphrase =
+            derive_address_from_path(mnemonic, "TREZOR", ChainType::Evm, "m/44'/60'/0'/0/0")
+                .unwrap();
+        let multi = generate_from_mnemonic_all("枪 疫 霉 尝 俩 闹 饿 贤 枪 疫 霉 卿").unwrap();
+        let deployer = generate_raw_evm_address().unwrap();
+        let sender = parse_account(ChainType::Evm, &deployer.address).unwrap();
+        let hit = ContractHit {
+            nonce: 3,
+            contract_address: create_contract_address(&sender, 3),
+            deployer,
+        };
+        save_address_to_file(filename, &raw, true).unwrap();
+        save_address_to_file(filename, &with_passphrase, false).unwrap();
+        save_multi_address_to_file(filename, &multi, ChainType::Sol).unwrap();
+        save_contract_hit_to_file(filename, &hit).unwrap();
+        let content = std::fs::read_to_string(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+
+        let reports = verify_records(&content, Some("TREZOR"));
+        assert_eq!(reports.len(), 4);
+        assert!(
+            reports
+                .iter()
+                .all(|report| report.status == VerifyStatus::Ok),
+            "{:?}",
+            reports
+        );
+        assert_eq!(reports[0].line, 2);
+        assert!(reports[2].header.contains("Hit Chain: SOL"));
+
+        // 缺少密码时无法校验带密码的记录
+        let statuses: Vec<_> = verify_records(&content, None)
+            .into_iter()
+            .map(|r| r.status)
+            .collect();
+        assert!(matches!(statuses[1], VerifyStatus::Unparseable(_)));
+        assert_eq!(statuses[2], VerifyStatus::Ok);
+
+        // 篡改后的记录
+        let evm = &multi.addresses[&ChainType::Evm];
+        let tampered = content
+            .replace(&raw.public_key, &with_passphrase.public_key)
+            .replace(
+                evm.private_key.expose_secret(),
+                raw.private_key.expose_secret(),
+            );
+        let statuses: Vec<_> = verify_records(&tampered, Some("TREZOR"))
+            .into_iter()
+            .map(|r| r.status)
+            .collect();
+        assert!(matches!(&statuses[0], VerifyStatus::Mismatch(reason) if reason.contains("公钥")));
+        assert_eq!(statuses[1], VerifyStatus::Ok);
+        assert!(matches!(statuses[2], VerifyStatus::Mismatch(_)));
+        let wrong_contract = content.replace(&hit.contract_address, &evm.address);
+        assert!(matches!(
+            verify_records(&wrong_contract, None)[3].status,
+            VerifyStatus::Mismatch(_)
+        ));
+
+        // 早期版本没有链名的记录、不支持或不完整的记录
+        let tron = derive_address_at(mnemonic, "", ChainType::Tron, 0, 0).unwrap();
+        let legacy = format!(
+            "═══\n[VANITY] 2025-12-07 10:30:45\nAddress: {}\nPrivate Key: {}\nPublic Key: {}\nMnemonic: {}\n═══\n\n═══\n[CREATE2] 2025-12-07 10:30:45 | Chain: EVM\nSalt: 00\n═══\n═══\n[VANITY] x\nAddress 1\n═══\n═══\n[NORMAL] x\n",
+            tron.address,
+            tron.private_key.expose_secret(),
+            tron.public_key,
+            mnemonic
+        );
+        let statuses: Vec<_> = verify_records(&legacy, None)
+            .into_iter()
+            .map(|r| r.status)
+            .collect();
+        assert_eq!(statuses.len(), 4);
+        assert_eq!(statuses[0], VerifyStatus::Ok);
+        assert!(statuses[1..]
+            .iter()
+            .all(|status| matches!(status, VerifyStatus::Unparseable(_))));
+    }
+
+    #[test]
+    fn test_bip39_passphrase() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        // BIP39 官方向量（密码 TREZOR）
+        assert_eq!(
+            hex::encode(mnemonic_to_seed(mnemonic, "TREZOR").unwrap()),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+
+        let options = MnemonicOptions {
+            passphrase: Some("TREZOR".into()),
+            ..Default::default()
+        };
+        let multi = generate_from_mnemonic_with_options(mnemonic, &options).unwrap();
+        assert!(multi.passphrase_used);
+        assert_eq!(
+            multi.addresses[&ChainType::Tron].address,
+            "TAyDUYP5rcf56xFwrg8cU1qQwvnWpkeapM"
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Evm].address,
+            "0x9c32F71D4DB8Fb9e1A58B0a80dF79935e7256FA6"
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Evm].private_key.expose_secret(),
+            "62f1d86b246c81bdd8f6c166d56896a4a5e1eddbcaebe06480e5c0bc74c28224"
+        );
+        assert_eq!(
+            multi.addresses[&ChainType::Sol].address,
+            "9md2ZNCYw622wTzgHn71kh9T6mFSoB4USnA4QdvY8oM4"
+        );
+        for (&chain, addr) in &multi.addresses {
+            assert!(addr.passphrase_used);
+            let path = addr.derivation_path.as_deref().unwrap();
+            assert_eq!(
+                derive_address_from_path(mnemonic, "TREZOR", chain, path)
+                    .unwrap()
+                    .address,
+                addr.address
+            );
+            // 不带密码得到的是另一组地址
+            assert_ne!(
+                derive_address_from_path(mnemonic, "", chain, path)
+                    .unwrap()
+                    .address,
+                addr.address
+            );
+        }
+        assert!(
+            !generate_from_mnemonic_all(mnemonic)
+                .unwrap()
+                .passphrase_used
+        );
+
+        // 搜索命中可以用助记词 + 密码恢复，文件只记录使用了密码
+        let chains = [ChainType::Tron, ChainType::Evm, ChainType::Sol];
+        for mode in [SearchMode::Mnemonic, SearchMode::HdScan] {
+            let mut generator = CandidateGenerator::new(mode, &chains);
+            generator.set_hd_range(HdScanRange {
+                accounts: 1,
+                indices: 2,
+            });
+            generator.set_mnemonic_options(options.clone());
+            for addr in generator.next_batch(2).unwrap().into_iter().flatten() {
+                assert!(addr.passphrase_used);
+                let path = addr.derivation_path.clone().unwrap();
+                let fresh = derive_address_from_path(
+                    addr.mnemonic.as_ref().unwrap().expose_secret(),
+                    "TREZOR",
+                    addr.chain,
+                    &path,
+                )
+                .unwrap();
+                assert_eq!(fresh.address, addr.address);
+            }
+        }
+
+        let file =
+            std::env::temp_dir().join(format!("tron_vanity_passphrase_{}.txt", std::process::id()));
+        let filename = file.to_str().unwrap();
+        save_multi_address_to_file(filename, &multi, ChainType::Tron).unwrap();
+        save_address_to_file(filename, &multi.addresses[&ChainType::Sol], true).unwrap();
+        let content = std::fs::read_to_string(&file).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(
+            content
+                .matches(&format!("Passphrase: {}", PASSPHRASE_NOTICE))
+                .count(),
+            2
+        );
+        assert!(!content.contains("TREZOR"));
+    }
+
+    #[test]
+    fn test_beauty_ranking_keeps_top_n() {
+        let addr = generate_raw_tron_address().unwrap();
+        let mut ranking = BeautyRanking::new(3);
+        for score in [10, 50, 30, 40, 20] {
+            ranking.push(ScoredAddress {
+                address: addr.clone(),
+                score: BeautyScore {
+                    score,
+                    features: Vec::new(),
+                },
+            });
+        }
+        let scores: Vec<u32> = ranking.entries().iter().map(|e| e.score.score).collect();
+        assert_eq!(scores, vec![50, 40, 30]);
+        assert!(!ranking.push(ScoredAddress {
+            address: addr,
+            score: BeautyScore::default(),
+        }));
+    }
+
+    #[test]
+    fn test_keystore_v3_vectors() {
+        // Web3 Secret Storage 规范测试向量，密码 testpassword
+        let pbkdf2 = r#"{"address":"008aeeda4d805471df9b2a5b0f38a0c3bcba786b","crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"6087dab2f9fdbbfaddc31a909735c1e6"},"ciphertext":"5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46","kdf":"pbkdf2","kdfparams":{"c":262144,"dklen":32,"prf":"hmac-sha256","salt":"ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"},"mac":"517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"},"id":"3198bc9c-6672-5ab3-d995-4942343ae5b6","version":3}"#;
+        // 同一私钥、salt、iv 按 geth --lightkdf 参数（n=4096, r=8, p=6）独立计算
+        let scrypt = r#"{"crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"83dbcc02d8ccb40e466191a123791e0e"},"ciphertext":"584f4eb2783472ec149b334c22d77ddbf118a0783ea24e66ca8a7883cd2b4bf5","kdf":"scrypt","kdfparams":{"dklen":32,"n":4096,"r":8,"p":6,"salt":"ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"},"mac":"860ad3ce9642f99d709abc696e25ede7cd35daf6a6041e975d14c53dae118886"},"id":"3198bc9c-6672-5ab3-d995-4942343ae5b6","version":3}"#;
+        let expected = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+        assert_eq!(
+            hex::encode(decrypt_keystore(pbkdf2, "testpassword").unwrap()),
+            expected
+        );
+        assert_eq!(
+            hex::encode(decrypt_keystore(scrypt, "testpassword").unwrap()),
+            expected
+        );
+        assert!(matches!(
+            decrypt_keystore(pbkdf2, "wrong"),
+            Err(VanityError::WrongPassword)
+        ));
+        // 不可信文件中过大的 scrypt 参数与 c = 0 在派生前拒绝
+        for forged in [
+            scrypt.replace(r#""n":4096"#, r#""n":2097152"#),
+            scrypt.replace(r#""n":4096,"r":8"#, r#""n":1048576,"r":16"#),
+            scrypt.replace(r#""p":6"#, r#""p":4096"#),
+            pbkdf2.replace(r#""c":262144"#, r#""c":0"#),
+            pbkdf2.replace(r#""c":262144"#, r#""c":4000000000"#),
+        ] {
+            assert!(matches!(
+                decrypt_keystore(&forged, "testpassword"),
+                Err(VanityError::Keystore(_))
+            ));
+        }
+
+        let evm = import_keystore(ChainType::Evm, pbkdf2, "testpassword").unwrap();
+        assert_eq!(evm.address, "0x008AeEda4D805471dF9b2A5B0f38A0C3bCBA786b");
+        assert_eq!(evm.private_key.expose_secret(), expected);
+        // 文件中的 address 是 EVM 账户，按 TRON 导入时账户相同，也应通过
+        let tron = import_keystore(ChainType::Tron, pbkdf2, "testpassword").unwrap();
+        assert_eq!(
+            hex::encode(parse_account(ChainType::Tron, &tron.address).unwrap()),
+            "008aeeda4d805471df9b2a5b0f38a0c3bcba786b"
+        );
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let kdfs = [
+            KeystoreKdf::Scrypt {
+                log_n: 10,
+                r: 8,
+                p: 1,
+            },
+            KeystoreKdf::Pbkdf2 { iterations: 1000 },
+        ];
+        for chain in [ChainType::Tron, ChainType::Evm] {
+            for kdf in kdfs {
+                let addr = generate_raw_address(chain).unwrap();
+                let key: [u8; 32] = hex::decode(addr.private_key.expose_secret())
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                let json = encrypt_keystore(chain, &key, "密码 password", kdf).unwrap();
+                assert!(!json.contains(addr.private_key.expose_secret()));
+                assert_eq!(decrypt_keystore(&json, "密码 password").unwrap(), key);
+                assert!(matches!(
+                    decrypt_keystore(&json, "password"),
+                    Err(VanityError::WrongPassword)
+                ));
+
+                let imported = import_keystore(chain, &json, "密码 password").unwrap();
+                assert_eq!(imported.address, addr.address);
+                assert_eq!(imported.public_key, addr.public_key);
+
+                // address 字段被替换为其他账户时拒绝导入
+                let other = generate_raw_address(chain).unwrap();
+                let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+                let recorded = value["address"].as_str().unwrap().to_string();
+                let forged_address = match chain {
+                    ChainType::Evm => other.address.trim_start_matches("0x").to_lowercase(),
+                    _ => other.address.clone(),
+                };
+                let forged = json.replace(&recorded, &forged_address);
+                assert!(matches!(
+                    import_keystore(chain, &forged, "密码 password"),
+                    Err(VanityError::Keystore(_))
+                ));
+            }
+        }
+
+        let sol = generate_raw_sol_address().unwrap();
+        assert!(matches!(
+            encrypt_keystore(ChainType::Sol, &[1u8; 32], "password", KeystoreKdf::PBKDF2),
+            Err(VanityError::UnsupportedChain(ChainType::Sol))
+        ));
+
+        let dir = std::env::temp_dir().join(format!("tron_vanity_keystore_{}", std::process::id()));
+        let addr = generate_raw_evm_address().unwrap();
+        let path =
+            save_keystore_to_file(dir.to_str().unwrap(), &addr, "password", kdfs[0]).unwrap();
+        let name = path.file_name().unwrap().to_str().unwrap().to_string();
+        let json = std::fs::read_to_string(&path).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert!(save_keystore_to_file(dir.to_str().unwrap(), &sol, "password", kdfs[0]).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(name.starts_with("UTC--"));
+        assert!(name.ends_with(&addr.address[2..].to_lowercase()));
+        assert!(!json.contains(addr.private_key.expose_secret()));
+        assert_eq!(
+            import_keystore(ChainType::Evm, &json, "password")
+                .unwrap()
+                .address,
+            addr.address
+        );
+    }
+
+    #[test]
+    fn test_keystore_hit_keeps_secrets_off_disk() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let multi =
+            generate_from_mnemonic_with_options(mnemonic, &MnemonicOptions::default()).unwrap();
+        let dir =
+            std::env::temp_dir().join(format!("tron_vanity_keystore_hit_{}", std::process::id()));
+        let results = dir.join("results.txt");
+        let keystore_dir = dir.join("keystore");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let saved: Vec<_> = multi
+            .addresses
+            .values()
+            .map(|addr| {
+                save_hit_with_keystore(
+                    results.to_str().unwrap(),
+                    keystore_dir.to_str().unwrap(),
+                    addr,
+                    "password",
+                    KeystoreKdf::Pbkdf2 { iterations: 1000 },
+                )
+                .unwrap()
+            })
+            .collect();
+        let record = std::fs::read_to_string(&results).unwrap();
+        let keystores = std::fs::read_dir(&keystore_dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // TRON/EVM 只写 Keystore，SOL 记录不含助记词
+        assert_eq!(keystores, 2);
+        for ((chain, addr), path) in multi.addresses.iter().zip(&saved) {
+            let in_keystore = path.is_some();
+            assert_eq!(in_keystore, *chain != ChainType::Sol);
+            assert_eq!(
+                record.contains(addr.private_key.expose_secret()),
+                !in_keystore
+            );
+        }
+        assert!(!record.contains(mnemonic));
+    }
+    #[test]
+    fn test_sol_keypair_export() {
+        // RFC 8032 测试 1 的种子与公钥
+        let seed: [u8; 32] =
+            hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let addr = sol_address_from_seed(&seed, None).unwrap();
+        assert_eq!(addr.address, "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z");
+        let keypair = sol_keypair_bytes(&addr).unwrap();
+        assert_eq!(keypair[..32], seed);
+        assert_eq!(
+            hex::encode(&keypair[32..]),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        let json = sol_keypair_json(&addr).unwrap();
+        assert!(json.expose_secret().starts_with("[157,97,177,157,"));
+        assert!(json.expose_secret().ends_with(",247,7,81,26]"));
+        assert_eq!(
+            sol_phantom_secret(&addr).unwrap().expose_secret(),
+            "49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw"
+        );
+
+        // 导出的密钥对导回后，公钥与 generate_sol_address 计算的一致
+        for _ in 0..4 {
+            let addr = generate_sol_address().unwrap();
+            let keypair = sol_keypair_bytes(&addr).unwrap();
+            assert_eq!(hex::encode(&keypair[32..]), addr.public_key);
+            assert_eq!(bs58::encode(&keypair[32..]).into_string(), addr.address);
+
+            let json = sol_keypair_json(&addr).unwrap();
+            let bytes: Vec<u8> = serde_json::from_str(json.expose_secret()).unwrap();
+            assert_eq!(bytes, keypair.to_vec());
+            for exported in [json, sol_phantom_secret(&addr).unwrap()] {
+                let imported = import_sol_keypair(exported.expose_secret()).unwrap();
+                assert_eq!(imported.address, addr.address);
+                assert_eq!(imported.public_key, addr.public_key);
+                assert_eq!(
+                    imported.private_key.expose_secret(),
+                    addr.private_key.expose_secret()
+                );
+            }
+        }
+
+        // 公钥与种子不符、长度不对、非 SOL 结果均拒绝
+        let mut forged = keypair;
+        forged[63] ^= 1;
+        assert!(import_sol_keypair(&bs58::encode(forged).into_string()).is_err());
+        assert!(import_sol_keypair("[1,2,3]").is_err());
+        assert!(matches!(
+            sol_keypair_bytes(&generate_raw_tron_address().unwrap()),
+            Err(VanityError::UnsupportedChain(ChainType::Tron))
+        ));
+
+        let dir =
+            std::env::temp_dir().join(format!("tron_vanity_sol_keypair_{}", std::process::id()));
+        let path = save_sol_keypair_to_file(dir.to_str().unwrap(), &addr).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let file = dir.join("verify.txt");
+        save_address_to_file(file.to_str().unwrap(), &addr, true).unwrap();
+        let reports = verify_file(file.to_str().unwrap(), None).unwrap();
+        let record = std::fs::read_to_string(&file).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            path.file_name().unwrap().to_str().unwrap(),
+            format!("{}.json", addr.address)
+        );
+        assert_eq!(import_sol_keypair(&content).unwrap().address, addr.address);
+        // 默认记录格式不包含 Phantom 私钥
+        assert!(!record.contains("Phantom"));
+        assert_eq!(reports[0].status, VerifyStatus::Ok);
     }
 }
@@ -12,6 +12,12 @@ pub struct SystemMonitor {
     sys: System,
 }
 
+impl Default for SystemMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SystemMonitor {
     pub fn new() -> Self {
         let mut sys = System::new_all();